@@ -0,0 +1,273 @@
+use crate::config::CONFIG;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MANIFEST_FILE_NAME: &str = "snapshot.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    #[serde(skip)]
+    pub id: String,
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub created_at: u64,
+    pub command: String,
+    pub files: Vec<String>,
+}
+
+impl Snapshot {
+    pub fn file_path(&self, file_name: &str) -> PathBuf {
+        self.path.join(file_name)
+    }
+}
+
+/// Copies `files` into a new snapshot in `backup_directory` and removes the oldest
+/// snapshots beyond the configured `backup_count`. Does nothing if backups are disabled.
+pub fn create_snapshot(
+    backup_directory: &Path,
+    command: &str,
+    files: &[PathBuf],
+) -> Result<Option<Snapshot>, String> {
+    let config = CONFIG.get().unwrap();
+    if config.backup_count == 0 || files.is_empty() {
+        return Ok(None);
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| err.to_string())?;
+
+    let id = format!(
+        "{}-{:03}",
+        format_timestamp(now.as_secs(), "", "-", ""),
+        now.subsec_millis()
+    );
+    let (id, path) = create_snapshot_directory(backup_directory, &id)?;
+
+    let mut file_names = Vec::new();
+    for file in files {
+        let file_name = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("Could not back up {}: invalid file name", file.display()))?
+            .to_string();
+        fs::copy(file, path.join(&file_name))
+            .map_err(|err| format!("Could not back up {}: {}", file.display(), err))?;
+        file_names.push(file_name);
+    }
+
+    let snapshot = Snapshot {
+        id,
+        path,
+        created_at: now.as_secs(),
+        command: command.to_string(),
+        files: file_names,
+    };
+
+    fs::write(
+        snapshot.path.join(MANIFEST_FILE_NAME),
+        serde_json::to_string_pretty(&snapshot).map_err(|err| err.to_string())?,
+    )
+    .map_err(|err| err.to_string())?;
+
+    rotate_snapshots(backup_directory, config.backup_count)?;
+
+    Ok(Some(snapshot))
+}
+
+/// Creates the directory of a new snapshot named `id`. Snapshots taken within the same
+/// millisecond get a counter appended, so they never share a directory.
+fn create_snapshot_directory(
+    backup_directory: &Path,
+    id: &str,
+) -> Result<(String, PathBuf), String> {
+    let error = |path: &Path, err: std::io::Error| {
+        format!(
+            "Could not create backup directory {}: {}",
+            path.display(),
+            err
+        )
+    };
+
+    fs::create_dir_all(backup_directory).map_err(|err| error(backup_directory, err))?;
+
+    let mut unique_id = id.to_string();
+    for counter in 1.. {
+        let path = backup_directory.join(&unique_id);
+        match fs::create_dir(&path) {
+            Ok(()) => return Ok((unique_id, path)),
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+                unique_id = format!("{}-{}", id, counter);
+            }
+            Err(err) => return Err(error(&path, err)),
+        }
+    }
+
+    unreachable!()
+}
+
+/// Returns all snapshots in `backup_directory`, newest first.
+pub fn get_snapshots(backup_directory: &Path) -> Result<Vec<Snapshot>, String> {
+    if !backup_directory.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = backup_directory.read_dir().map_err(|err| err.to_string())?;
+    let mut snapshots = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let manifest = path.join(MANIFEST_FILE_NAME);
+        if !manifest.is_file() {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&manifest).map_err(|err| err.to_string())?;
+        let mut snapshot = serde_json::from_str::<Snapshot>(&contents)
+            .map_err(|err| format!("Could not read {}: {}", manifest.display(), err))?;
+
+        let Some(id) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+
+        snapshot.id = id;
+        snapshot.path = path;
+        snapshots.push(snapshot);
+    }
+
+    snapshots.sort_by(|a, b| b.id.cmp(&a.id));
+
+    Ok(snapshots)
+}
+
+fn rotate_snapshots(backup_directory: &Path, keep: usize) -> Result<(), String> {
+    for snapshot in get_snapshots(backup_directory)?.into_iter().skip(keep) {
+        fs::remove_dir_all(&snapshot.path).map_err(|err| {
+            format!(
+                "Could not remove old backup {}: {}",
+                snapshot.path.display(),
+                err
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Formats a unix timestamp as a UTC date and time, using the given separators
+/// between the date parts, between date and time, and between the time parts.
+pub fn format_timestamp(
+    timestamp: u64,
+    date_separator: &str,
+    separator: &str,
+    time_separator: &str,
+) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds = timestamp % 86400;
+
+    // Civil date from days since the epoch, see https://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}{ds}{:02}{ds}{:02}{s}{:02}{ts}{:02}{ts}{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+        ds = date_separator,
+        s = separator,
+        ts = time_separator,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0, "-", " ", ":"), "1970-01-01 00:00:00");
+        assert_eq!(
+            format_timestamp(1_709_210_096, "", "-", ""),
+            "20240229-123456"
+        );
+    }
+
+    #[test]
+    fn gives_snapshots_in_the_same_millisecond_their_own_directory() {
+        let backup_directory = env::temp_dir().join(format!(
+            "{}-backup-test-{}",
+            env!("CARGO_PKG_NAME"),
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&backup_directory);
+
+        let ids = (0..3)
+            .map(|_| {
+                create_snapshot_directory(&backup_directory, "20240229-123456-789")
+                    .unwrap()
+                    .0
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            [
+                "20240229-123456-789",
+                "20240229-123456-789-1",
+                "20240229-123456-789-2"
+            ]
+        );
+
+        for id in &ids {
+            let snapshot = Snapshot {
+                id: id.clone(),
+                path: backup_directory.join(id),
+                created_at: 1_709_210_096,
+                command: format!("add {}", id),
+                files: vec!["en.json".to_string()],
+            };
+            fs::write(
+                snapshot.path.join(MANIFEST_FILE_NAME),
+                serde_json::to_string(&snapshot).unwrap(),
+            )
+            .unwrap();
+        }
+
+        let snapshots = get_snapshots(&backup_directory).unwrap();
+        let listed = snapshots
+            .iter()
+            .map(|snapshot| snapshot.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            listed,
+            [
+                "20240229-123456-789-2",
+                "20240229-123456-789-1",
+                "20240229-123456-789"
+            ]
+        );
+        assert_eq!(snapshots[0].command, "add 20240229-123456-789-2");
+
+        rotate_snapshots(&backup_directory, 1).unwrap();
+        let remaining = get_snapshots(&backup_directory).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, "20240229-123456-789-2");
+
+        fs::remove_dir_all(&backup_directory).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+use crate::validation::{Issue, IssueKind, Validation};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+// Bump this when making a breaking change to the baseline file.
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub issues: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    #[serde(rename = "type")]
+    pub kind: IssueKind,
+    pub locale: String,
+    pub key: String,
+}
+
+/// The result of comparing a validation to a baseline.
+#[derive(Debug, Clone, Default)]
+pub struct BaselineComparison {
+    /// Issues that were already known, and are no longer reported.
+    pub known: Vec<Issue>,
+    /// Entries of the baseline that no longer occur, so they can be removed from it.
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn from_validation(validation: &Validation) -> Self {
+        let mut issues = Vec::<BaselineEntry>::new();
+
+        for issue in &validation.issues {
+            let fingerprint = issue.fingerprint();
+            if issues.iter().any(|entry| entry.fingerprint == fingerprint) {
+                continue;
+            }
+
+            issues.push(BaselineEntry {
+                fingerprint,
+                kind: issue.kind,
+                locale: issue.locale.clone(),
+                key: issue.key.clone(),
+            });
+        }
+
+        // Keep the file stable, so changes to it are easy to review.
+        issues.sort_by(|a, b| {
+            (&a.locale, &a.key, a.kind.name()).cmp(&(&b.locale, &b.key, b.kind.name()))
+        });
+
+        Self {
+            version: BASELINE_VERSION,
+            issues,
+        }
+    }
+
+    /// Removes every issue in the baseline from `validation`, and records which were removed
+    /// and which entries of the baseline are fixed.
+    pub fn apply(&self, validation: &mut Validation) {
+        let fingerprints = self
+            .issues
+            .iter()
+            .map(|entry| entry.fingerprint.as_str())
+            .collect::<HashSet<_>>();
+
+        let (known, issues) = validation
+            .issues
+            .drain(..)
+            .partition::<Vec<_>, _>(|issue| fingerprints.contains(issue.fingerprint().as_str()));
+        validation.issues = issues;
+
+        let remaining = known.iter().map(Issue::fingerprint).collect::<HashSet<_>>();
+        let fixed = self
+            .issues
+            .iter()
+            .filter(|entry| !remaining.contains(&entry.fingerprint))
+            .cloned()
+            .collect();
+
+        validation.baseline = Some(BaselineComparison { known, fixed });
+    }
+}
+
+pub fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let contents = fs::read_to_string(path).map_err(|err| {
+        format!(
+            "Could not read baseline {}: {}. Create it with 'validate --write-baseline'",
+            path.display(),
+            err
+        )
+    })?;
+
+    let baseline = serde_json::from_str::<Baseline>(&contents)
+        .map_err(|err| format!("Could not parse baseline {}: {}", path.display(), err))?;
+
+    if baseline.version != BASELINE_VERSION {
+        return Err(format!(
+            "Baseline {} has unsupported version {}, regenerate it with 'validate --write-baseline'",
+            path.display(),
+            baseline.version
+        ));
+    }
+
+    Ok(baseline)
+}
+
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Could not create directory {}: {}", parent.display(), err))?;
+    }
+
+    let contents = serde_json::to_string_pretty(baseline).unwrap();
+    fs::write(path, contents + "\n")
+        .map_err(|err| format!("Could not write baseline {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::issue;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::diagnostics::Location;
+    use crate::validation::LocaleFile;
+
+    fn test_issue(kind: IssueKind, locale: &LocaleFile, key: &str, message: &str) -> Issue {
+        let span = translation(locale, key).span;
+        let location = Location::in_document(&locale.file, &locale.document, span);
+
+        issue(kind, locale, key, message.to_string(), location)
+    }
+
+    fn validation(issues: Vec<Issue>) -> Validation {
+        Validation {
+            default_locale: locale_file("en", "{}"),
+            locales: Vec::new(),
+            issues,
+            suppressed: Vec::new(),
+            baseline: None,
+        }
+    }
+
+    #[test]
+    fn fingerprints_do_not_depend_on_lines() {
+        let before = locale_file("de", "{a: ''}");
+        let after = locale_file("de", "{\n  z: 'Z',\n\n  a: ''\n}");
+
+        let moved = |locale| test_issue(IssueKind::EmptyValue, locale, "a", "empty");
+        assert_eq!(moved(&before).fingerprint(), moved(&after).fingerprint());
+
+        let fingerprint = moved(&before).fingerprint();
+        assert_eq!(fingerprint.len(), 16);
+        assert_ne!(
+            test_issue(IssueKind::EmptyValue, &after, "z", "empty").fingerprint(),
+            fingerprint
+        );
+        assert_ne!(
+            test_issue(IssueKind::EmptyValue, &before, "a", "other").fingerprint(),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn records_each_issue_once_in_a_stable_order() {
+        let de = locale_file("de", "{a: '', b: ''}");
+        let fr = locale_file("fr", "{a: ''}");
+
+        let baseline = Baseline::from_validation(&validation(vec![
+            test_issue(IssueKind::EmptyValue, &fr, "a", "empty"),
+            test_issue(IssueKind::EmptyValue, &de, "b", "empty"),
+            test_issue(IssueKind::EmptyValue, &de, "a", "empty"),
+            test_issue(IssueKind::EmptyValue, &de, "a", "empty"),
+        ]));
+
+        let entries = baseline
+            .issues
+            .iter()
+            .map(|entry| (entry.locale.as_str(), entry.key.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(baseline.version, BASELINE_VERSION);
+        assert_eq!(entries, [("de", "a"), ("de", "b"), ("fr", "a")]);
+    }
+
+    #[test]
+    fn removes_known_issues_and_reports_fixed_entries() {
+        let old = locale_file("de", "{a: '', b: ''}");
+        let baseline = Baseline::from_validation(&validation(vec![
+            test_issue(IssueKind::EmptyValue, &old, "a", "empty"),
+            test_issue(IssueKind::EmptyValue, &old, "b", "empty"),
+        ]));
+
+        let new = locale_file("de", "{\n  a: '',\n  b: 'B',\n  c: ''\n}");
+        let mut validation = validation(vec![
+            test_issue(IssueKind::EmptyValue, &new, "a", "empty"),
+            test_issue(IssueKind::EmptyValue, &new, "c", "empty"),
+        ]);
+        baseline.apply(&mut validation);
+
+        let keys = |issues: &[Issue]| {
+            issues
+                .iter()
+                .map(|issue| issue.key.clone())
+                .collect::<Vec<_>>()
+        };
+        let comparison = validation.baseline.as_ref().unwrap();
+        assert_eq!(keys(&validation.issues), ["c"]);
+        assert_eq!(keys(&comparison.known), ["a"]);
+        assert_eq!(comparison.fixed.len(), 1);
+        assert_eq!(comparison.fixed[0].key, "b");
+    }
+}
@@ -0,0 +1,285 @@
+use crate::checks::{Translation, issue, value_issue, value_location};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name, locale_language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsistencyCheck {
+    // Leading and trailing whitespace is the same as in the default locale.
+    SurroundingWhitespace,
+    // No double spaces, unless the default locale has them too.
+    DoubleSpaces,
+    // The value ends with the same kind of punctuation as in the default locale.
+    EndingPunctuation,
+    // The value has as many line breaks as in the default locale.
+    LineBreaks,
+}
+
+impl ConsistencyCheck {
+    pub const ALL: [ConsistencyCheck; 4] = [
+        ConsistencyCheck::SurroundingWhitespace,
+        ConsistencyCheck::DoubleSpaces,
+        ConsistencyCheck::EndingPunctuation,
+        ConsistencyCheck::LineBreaks,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Punctuation {
+    FullStop,
+    Question,
+    Exclamation,
+    Colon,
+    Semicolon,
+    Comma,
+    Ellipsis,
+}
+
+impl Punctuation {
+    /// The kind of punctuation `value` ends with, treating the equivalents in other
+    /// scripts, such as `。` and `؟`, the same.
+    fn of(value: &str) -> Option<(Punctuation, &str)> {
+        let value = value.trim_end();
+        if value.ends_with("...") {
+            return Some((Punctuation::Ellipsis, "..."));
+        }
+
+        let c = value.chars().next_back()?;
+        let punctuation = match c {
+            '…' => Punctuation::Ellipsis,
+            '.' | '。' | '．' | '।' | '۔' | '።' | '။' | '܂' => Punctuation::FullStop,
+            '?' | '？' | '؟' | '\u{37e}' | '՞' | '፧' => Punctuation::Question,
+            '!' | '！' | '՜' => Punctuation::Exclamation,
+            ':' | '：' => Punctuation::Colon,
+            ';' | '；' | '؛' => Punctuation::Semicolon,
+            ',' | '，' | '、' | '،' => Punctuation::Comma,
+            _ => return None,
+        };
+
+        Some((punctuation, &value[value.len() - c.len_utf8()..]))
+    }
+}
+
+// Languages written without a full stop at the end of a sentence.
+const WITHOUT_FULL_STOP: [&str; 2] = ["lo", "th"];
+
+/// Runs the enabled consistency checks, comparing the whitespace and punctuation of a
+/// translation to the value in the default locale.
+pub fn check(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+    checks: &[ConsistencyCheck],
+) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let file = file_name(&locale.file);
+    let default_file = file_name(&default_locale.file);
+    let key = translation.key;
+
+    let mismatch =
+        |kind, message| value_issue(kind, default_locale, locale, default, translation, message);
+
+    if checks.contains(&ConsistencyCheck::SurroundingWhitespace) {
+        let leading = |value: &str| value.len() - value.trim_start().len();
+        let trailing = |value: &str| value.len() - value.trim_end().len();
+
+        let expected = &default.value[..leading(default.value)];
+        let actual = &translation.value[..leading(translation.value)];
+        if expected != actual {
+            issues.push(mismatch(
+                IssueKind::WhitespaceMismatch,
+                format!(
+                    "{} has leading whitespace {:?} in '{}' instead of {:?} as in {}",
+                    file, actual, key, expected, default_file
+                ),
+            ));
+        }
+
+        let expected = &default.value[default.value.len() - trailing(default.value)..];
+        let actual = &translation.value[translation.value.len() - trailing(translation.value)..];
+        if expected != actual {
+            issues.push(mismatch(
+                IssueKind::WhitespaceMismatch,
+                format!(
+                    "{} has trailing whitespace {:?} in '{}' instead of {:?} as in {}",
+                    file, actual, key, expected, default_file
+                ),
+            ));
+        }
+    }
+
+    if checks.contains(&ConsistencyCheck::DoubleSpaces) && !default.value.contains("  ") {
+        // Leading and trailing spaces are left to the whitespace check.
+        let trimmed = translation.value.trim_start();
+        let start = translation.value.len() - trimmed.len();
+
+        if let Some(offset) = trimmed.trim_end().find("  ") {
+            issues.push(issue(
+                IssueKind::DoubleSpace,
+                locale,
+                key,
+                format!("{} has a double space in '{}'", file, key),
+                value_location(locale, translation, start + offset, 2),
+            ));
+        }
+    }
+
+    if checks.contains(&ConsistencyCheck::EndingPunctuation) {
+        let expected = Punctuation::of(default.value);
+        let actual = Punctuation::of(translation.value);
+        let language = locale_language(&locale.locale);
+
+        let equivalent = match (expected, actual) {
+            (Some((expected, _)), Some((actual, _))) => expected == actual,
+            (Some((Punctuation::FullStop, _)), None) => {
+                WITHOUT_FULL_STOP.contains(&language.as_str())
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        if !equivalent {
+            let describe = |punctuation: Option<(Punctuation, &str)>| match punctuation {
+                Some((_, text)) => format!("'{}'", text),
+                None => "no punctuation".to_string(),
+            };
+
+            issues.push(mismatch(
+                IssueKind::PunctuationMismatch,
+                format!(
+                    "{} ends '{}' with {} instead of {} as in {}",
+                    file,
+                    key,
+                    describe(actual),
+                    describe(expected),
+                    default_file
+                ),
+            ));
+        }
+    }
+
+    if checks.contains(&ConsistencyCheck::LineBreaks) {
+        let expected = default.value.matches('\n').count();
+        let actual = translation.value.matches('\n').count();
+        if expected != actual {
+            issues.push(mismatch(
+                IssueKind::LineBreakMismatch,
+                format!(
+                    "{} has {} line breaks in '{}' instead of {} as in {}",
+                    file, actual, key, expected, default_file
+                ),
+            ));
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::diagnostics::Severity;
+    use serde_json::json;
+
+    fn check_values(locale: &str, default: &str, value: &str) -> Vec<Issue> {
+        let en = locale_file("en", &json!({ "a": default }).to_string());
+        let other = locale_file(locale, &json!({ "a": value }).to_string());
+
+        check(
+            &en,
+            &other,
+            &translation(&en, "a"),
+            &translation(&other, "a"),
+            &ConsistencyCheck::ALL,
+        )
+    }
+
+    fn kinds(issues: &[Issue]) -> Vec<IssueKind> {
+        issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn accepts_consistent_values() {
+        assert!(check_values("fr", " Hello, world!\n", " Bonjour, monde !\n").is_empty());
+        assert!(check_values("ja", "Done.", "完了。").is_empty());
+        assert!(check_values("ar", "Sure?", "متأكد؟").is_empty());
+        assert!(check_values("th", "Saved.", "บันทึกแล้ว").is_empty());
+        assert!(check_values("de", "Loading...", "Laden…").is_empty());
+    }
+
+    #[test]
+    fn reports_whitespace_differences() {
+        let issues = check_values("de", "Name: ", " Name:");
+        assert_eq!(
+            kinds(&issues),
+            [IssueKind::WhitespaceMismatch, IssueKind::WhitespaceMismatch]
+        );
+        assert!(
+            issues[0]
+                .diagnostic
+                .message
+                .contains("leading whitespace \" \"")
+        );
+        assert!(
+            issues[1]
+                .diagnostic
+                .message
+                .contains("trailing whitespace \"\"")
+        );
+    }
+
+    #[test]
+    fn reports_double_spaces() {
+        let issues = check_values("de", "Save file", "Datei  speichern");
+        assert_eq!(kinds(&issues), [IssueKind::DoubleSpace]);
+        assert_eq!(issues[0].diagnostic.location.as_ref().unwrap().length, 2);
+
+        assert!(check_values("de", "A  B", "A  B").is_empty());
+    }
+
+    #[test]
+    fn reports_punctuation_and_line_break_differences() {
+        let issues = check_values("de", "Continue?", "Weiter.");
+        assert_eq!(kinds(&issues), [IssueKind::PunctuationMismatch]);
+        assert!(
+            issues[0]
+                .diagnostic
+                .message
+                .contains("with '.' instead of '?'")
+        );
+
+        let issues = check_values("de", "Saved.", "Gespeichert");
+        assert!(issues[0].diagnostic.message.contains("with no punctuation"));
+
+        let issues = check_values("de", "One\nTwo", "Eins Zwei");
+        assert_eq!(kinds(&issues), [IssueKind::LineBreakMismatch]);
+    }
+
+    #[test]
+    fn reports_warnings() {
+        let issues = check_values("de", " A  b", "A  b.\n");
+        assert_eq!(issues.len(), 4);
+        assert!(
+            issues
+                .iter()
+                .all(|issue| issue.severity() == Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn runs_only_the_enabled_checks() {
+        let en = locale_file("en", "{a: 'Hi '}");
+        let de = locale_file("de", "{a: 'Hallo.'}");
+
+        let issues = check(
+            &en,
+            &de,
+            &translation(&en, "a"),
+            &translation(&de, "a"),
+            &[ConsistencyCheck::LineBreaks],
+        );
+        assert!(issues.is_empty());
+    }
+}
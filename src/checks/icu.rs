@@ -0,0 +1,208 @@
+use crate::checks::{Translation, issue, value_issue, value_location};
+use crate::icu::{self, Argument};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageFormat {
+    // Values are plain text with placeholders.
+    Plain,
+    // Values are ICU MessageFormat patterns.
+    Icu,
+}
+
+/// Checks that a value is a valid ICU message, and that its `plural`, `selectordinal` and
+/// `select` arguments have an `other` branch.
+pub fn check(locale: &LocaleFile, translation: &Translation) -> Vec<Issue> {
+    let arguments = match icu::parse(translation.value) {
+        Ok(arguments) => arguments,
+        Err(err) => {
+            return vec![issue(
+                IssueKind::InvalidIcuMessage,
+                locale,
+                translation.key,
+                format!(
+                    "{} has an invalid ICU message in '{}': {}",
+                    file_name(&locale.file),
+                    translation.key,
+                    err.message
+                ),
+                value_location(locale, translation, err.offset, 1),
+            )];
+        }
+    };
+
+    arguments
+        .iter()
+        .filter(|argument| {
+            argument.has_branches()
+                && !argument
+                    .branches
+                    .iter()
+                    .any(|branch| branch.selector == "other")
+        })
+        .map(|argument| {
+            issue(
+                IssueKind::IcuMissingOther,
+                locale,
+                translation.key,
+                format!(
+                    "{} has a '{}' argument {{{}}} without an 'other' branch in '{}'",
+                    file_name(&locale.file),
+                    argument.kind,
+                    argument.name,
+                    translation.key
+                ),
+                value_location(locale, translation, argument.offset, 1),
+            )
+        })
+        .collect()
+}
+
+/// Checks that a translation uses the same argument names and types as the default locale.
+/// Values that cannot be parsed are left to `check`.
+pub fn check_arguments(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+) -> Option<Issue> {
+    let default_arguments = icu::parse(default.value).ok()?;
+    let translation_arguments = icu::parse(translation.value).ok()?;
+    let expected = signatures(&default_arguments);
+    let actual = signatures(&translation_arguments);
+
+    let mut differences = Vec::new();
+
+    for (name, kind) in &expected {
+        if actual.contains(&(name, kind)) {
+            continue;
+        }
+
+        match actual.iter().find(|(other, _)| other == name) {
+            Some((_, other_kind)) => differences.push(format!(
+                "{{{}}} is '{}' instead of '{}'",
+                name, other_kind, kind
+            )),
+            None => differences.push(format!("missing {{{}}}", name)),
+        }
+    }
+
+    for (name, _) in &actual {
+        let difference = format!("unexpected {{{}}}", name);
+        if !expected.iter().any(|(other, _)| other == name) && !differences.contains(&difference) {
+            differences.push(difference);
+        }
+    }
+
+    if differences.is_empty() {
+        return None;
+    }
+
+    Some(value_issue(
+        IssueKind::IcuArgumentMismatch,
+        default_locale,
+        locale,
+        default,
+        translation,
+        format!(
+            "{} has ICU arguments in '{}' that differ from {}: {}",
+            file_name(&locale.file),
+            translation.key,
+            file_name(&default_locale.file),
+            differences.join("; ")
+        ),
+    ))
+}
+
+/// The distinct combinations of argument name and type.
+fn signatures(arguments: &[Argument]) -> Vec<(&str, &str)> {
+    let mut signatures = Vec::new();
+    for argument in arguments {
+        let signature = (argument.name.as_str(), argument.kind_name());
+        if !signatures.contains(&signature) {
+            signatures.push(signature);
+        }
+    }
+
+    signatures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+
+    fn check_value(value: &str) -> Vec<Issue> {
+        let locale = locale_file("en", &format!("{{a: {:?}}}", value));
+        check(&locale, &translation(&locale, "a"))
+    }
+
+    fn check_values(default: &str, value: &str) -> Option<Issue> {
+        let en = locale_file("en", &format!("{{a: {:?}}}", default));
+        let fr = locale_file("fr", &format!("{{a: {:?}}}", value));
+
+        check_arguments(&en, &fr, &translation(&en, "a"), &translation(&fr, "a"))
+    }
+
+    #[test]
+    fn accepts_valid_messages() {
+        assert!(check_value("Hello {name}").is_empty());
+        assert!(check_value("{count, plural, one {# item} other {# items}}").is_empty());
+        assert!(check_value("{n, selectordinal, one {#st} other {#th}}").is_empty());
+        assert!(check_value("{gender, select, female {She} other {They}}").is_empty());
+        assert!(check_value("It's '{'literal'}' on {date, date, short}").is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_messages() {
+        let issues = check_value("Hello {name");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::InvalidIcuMessage);
+
+        let issues = check_value("{count, plurall, one {#} other {#}}");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::InvalidIcuMessage);
+        assert!(issues[0].diagnostic.message.contains("'plurall'"));
+    }
+
+    #[test]
+    fn reports_branches_without_other() {
+        let issues = check_value("{count, plural, one {# item} few {# items}}");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::IcuMissingOther);
+
+        let issues =
+            check_value("{gender, select, female {{count, plural, one {#} other {#}}} male {He}}");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].diagnostic.message.contains("{gender}"));
+    }
+
+    #[test]
+    fn compares_arguments_to_the_default_locale() {
+        assert!(
+            check_values(
+                "{name} has {count, plural, one {# item} other {# items}}",
+                "{count, plural, one {# article} many {# articles} other {# articles}} pour {name}"
+            )
+            .is_none()
+        );
+
+        let issue = check_values("{name} on {day, date}", "{nom} le {day, number}").unwrap();
+        assert_eq!(issue.kind, IssueKind::IcuArgumentMismatch);
+        assert!(issue.diagnostic.message.contains("missing {name}"));
+        assert!(
+            issue
+                .diagnostic
+                .message
+                .contains("{day} is 'number' instead of 'date'")
+        );
+        assert!(issue.diagnostic.message.contains("unexpected {nom}"));
+    }
+
+    #[test]
+    fn leaves_invalid_messages_to_check() {
+        assert!(check_values("{name}", "{name").is_none());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::checks::{Translation, issue, value_issue};
+use crate::diagnostics::Location;
+use crate::document::Span;
+use crate::suppression::glob_match;
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+const MARKER: &str = "tt-max-length";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthUnit {
+    // Unicode scalar values.
+    Characters,
+    // Characters as a reader sees them, so `é` written with a combining accent counts as one.
+    Graphemes,
+}
+
+impl LengthUnit {
+    pub fn length(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Characters => value.chars().count(),
+            LengthUnit::Graphemes => value.graphemes(true).count(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            LengthUnit::Characters => "characters",
+            LengthUnit::Graphemes => "graphemes",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LengthLimits {
+    pub unit: LengthUnit,
+    pub max_lengths: Vec<MaxLength>,
+    // How many times longer than the default locale a translation may be, or `None` to not
+    // compare lengths.
+    pub max_expansion: Option<f64>,
+}
+
+impl Default for LengthLimits {
+    fn default() -> Self {
+        Self {
+            unit: LengthUnit::Graphemes,
+            max_lengths: Vec::new(),
+            max_expansion: None,
+        }
+    }
+}
+
+/// The maximum length of the values of the keys matching one of the `keys` globs, in the
+/// given `locales`. Leaving out `locales` applies it to every locale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaxLength {
+    pub keys: Vec<String>,
+    #[serde(default)]
+    pub locales: Vec<String>,
+    pub max: usize,
+}
+
+/// The maximum length of `key` in `locale`. A `// tt-max-length: 20` comment above the key in
+/// the locale file or the default locale file takes precedence over the config, and of
+/// several matching limits in the config the smallest applies. Markers that are not a number
+/// are skipped, and reported by `check_marker`.
+fn max_length(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    key: &str,
+    limits: &LengthLimits,
+) -> Option<usize> {
+    let marker = [&locale.document, &default_locale.document]
+        .into_iter()
+        .filter_map(|document| document.marker(key, MARKER))
+        .find_map(|max| max.parse().ok());
+
+    marker.or_else(|| {
+        limits
+            .max_lengths
+            .iter()
+            .filter(|limit| limit.locales.is_empty() || limit.locales.contains(&locale.locale))
+            .filter(|limit| limit.keys.iter().any(|pattern| glob_match(pattern, key)))
+            .map(|limit| limit.max)
+            .min()
+    })
+}
+
+/// Checks that a value is not longer than the maximum length of its key.
+pub fn check(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    translation: &Translation,
+    limits: &LengthLimits,
+) -> Option<Issue> {
+    let max = max_length(default_locale, locale, translation.key, limits)?;
+    let length = limits.unit.length(translation.value);
+    if length <= max {
+        return None;
+    }
+
+    Some(issue(
+        IssueKind::MaxLengthExceeded,
+        locale,
+        translation.key,
+        format!(
+            "{} has a value of {} {} in '{}', more than the maximum of {}",
+            file_name(&locale.file),
+            length,
+            limits.unit.name(),
+            translation.key,
+            max
+        ),
+        Location::in_document(&locale.file, &locale.document, translation.span),
+    ))
+}
+
+/// Checks that a `// tt-max-length` marker above a key is a number.
+pub fn check_marker(locale: &LocaleFile, translation: &Translation) -> Option<Issue> {
+    let marker = locale.document.marker(translation.key, MARKER)?;
+    if marker.parse::<usize>().is_ok() {
+        return None;
+    }
+
+    let start = marker.as_ptr() as usize - locale.document.source.as_ptr() as usize;
+    Some(issue(
+        IssueKind::InvalidMarker,
+        locale,
+        translation.key,
+        format!(
+            "{} has a {} marker of '{}' that is not a number: '{}'",
+            file_name(&locale.file),
+            MARKER,
+            translation.key,
+            marker
+        ),
+        Location::in_document(
+            &locale.file,
+            &locale.document,
+            Span {
+                start,
+                end: start + marker.len(),
+            },
+        ),
+    ))
+}
+
+/// Checks that a translation is not longer than `max_expansion` times the value in the
+/// default locale.
+pub fn check_expansion(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+    limits: &LengthLimits,
+) -> Option<Issue> {
+    let max_expansion = limits.max_expansion?;
+    let expected = limits.unit.length(default.value);
+    let actual = limits.unit.length(translation.value);
+    let allowed = (expected as f64 * max_expansion).floor() as usize;
+    if actual <= allowed {
+        return None;
+    }
+
+    Some(value_issue(
+        IssueKind::ExpansionExceeded,
+        default_locale,
+        locale,
+        default,
+        translation,
+        format!(
+            "{} has a value of {} {} in '{}', more than the {} allowed for {} {} in {} ({}x)",
+            file_name(&locale.file),
+            actual,
+            limits.unit.name(),
+            translation.key,
+            allowed,
+            expected,
+            limits.unit.name(),
+            file_name(&default_locale.file),
+            max_expansion
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+
+    fn limits(max_lengths: Vec<MaxLength>) -> LengthLimits {
+        LengthLimits {
+            max_lengths,
+            ..LengthLimits::default()
+        }
+    }
+
+    #[test]
+    fn falls_back_from_invalid_markers() {
+        let en = locale_file(
+            "en",
+            "{\n  // tt-max-length: 5\n  a: 'Save',\n  b: 'Open'\n}",
+        );
+        let de = locale_file(
+            "de",
+            "{\n  // tt-max-length: five\n  a: 'Speichern',\n  // tt-max-length\n  b: 'Öffnen'\n}",
+        );
+        let limits = limits(vec![MaxLength {
+            keys: vec!["b".to_string()],
+            locales: Vec::new(),
+            max: 4,
+        }]);
+
+        assert_eq!(max_length(&en, &de, "a", &limits), Some(5));
+        assert_eq!(max_length(&en, &de, "b", &limits), Some(4));
+
+        let issue = check_marker(&de, &translation(&de, "a")).unwrap();
+        assert_eq!(issue.kind, IssueKind::InvalidMarker);
+        assert!(issue.diagnostic.message.ends_with("not a number: 'five'"));
+        let location = issue.diagnostic.location.unwrap();
+        assert_eq!(
+            (location.line, location.column, location.length),
+            (2, 21, 4)
+        );
+
+        assert!(check_marker(&de, &translation(&de, "b")).is_some());
+        assert!(check_marker(&en, &translation(&en, "a")).is_none());
+        assert!(check_marker(&en, &translation(&en, "b")).is_none());
+    }
+
+    #[test]
+    fn counts_graphemes_or_characters() {
+        // An `e` with a combining accent and a family emoji of five characters.
+        let value = "e\u{301}\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+
+        assert_eq!(LengthUnit::Graphemes.length(value), 2);
+        assert_eq!(LengthUnit::Characters.length(value), 7);
+    }
+
+    #[test]
+    fn prefers_markers_and_the_smallest_limit() {
+        let en = locale_file(
+            "en",
+            "{\n  // tt-max-length: 10\n  a: 'Save',\n  b: 'Open'\n}",
+        );
+        let de = locale_file(
+            "de",
+            "{\n  // tt-max-length: 12\n  a: 'Speichern',\n  b: 'Öffnen'\n}",
+        );
+        let limits = limits(vec![
+            MaxLength {
+                keys: vec!["*".to_string()],
+                locales: Vec::new(),
+                max: 8,
+            },
+            MaxLength {
+                keys: vec!["b".to_string()],
+                locales: vec!["de".to_string()],
+                max: 6,
+            },
+        ]);
+
+        assert_eq!(max_length(&en, &de, "a", &limits), Some(12));
+        assert_eq!(max_length(&en, &en, "a", &limits), Some(10));
+        assert_eq!(max_length(&en, &de, "b", &limits), Some(6));
+        assert_eq!(max_length(&en, &en, "b", &limits), Some(8));
+        assert_eq!(max_length(&en, &de, "c", &LengthLimits::default()), None);
+    }
+
+    #[test]
+    fn reports_values_over_the_maximum() {
+        let en = locale_file("en", "{\n  // tt-max-length: 6\n  a: 'Save'\n}");
+        let de = locale_file("de", "{a: 'Speichern'}");
+        let limits = LengthLimits::default();
+
+        let issue = check(&en, &de, &translation(&de, "a"), &limits).unwrap();
+        assert_eq!(issue.kind, IssueKind::MaxLengthExceeded);
+        assert!(issue.diagnostic.message.contains("9 graphemes"));
+        assert!(check(&en, &en, &translation(&en, "a"), &limits).is_none());
+    }
+
+    #[test]
+    fn reports_expansion_over_the_limit() {
+        let en = locale_file("en", "{a: 'Save file'}");
+        let de = locale_file("de", "{a: 'Datei speichern'}");
+        let check = |max_expansion| {
+            let limits = LengthLimits {
+                max_expansion,
+                ..LengthLimits::default()
+            };
+            check_expansion(
+                &en,
+                &de,
+                &translation(&en, "a"),
+                &translation(&de, "a"),
+                &limits,
+            )
+        };
+
+        assert!(check(None).is_none());
+        assert!(check(Some(2.0)).is_none());
+
+        let issue = check(Some(1.5)).unwrap();
+        assert_eq!(issue.kind, IssueKind::ExpansionExceeded);
+        assert!(
+            issue
+                .diagnostic
+                .message
+                .contains("more than the 13 allowed for 9")
+        );
+    }
+}
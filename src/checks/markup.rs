@@ -0,0 +1,296 @@
+use crate::checks::{Translation, issue, value_issue, value_location};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+
+// HTML elements without content, which have no closing tag.
+const VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagKind {
+    Open,
+    Close,
+    // `<br/>`, `<1/>` or a void element such as `<br>`.
+    SelfClosing,
+}
+
+/// A tag in a value, such as `<b>`, `<a href="...">` or a react-i18next `<0>`.
+#[derive(Debug, Clone)]
+pub struct Tag {
+    pub name: String,
+    pub kind: TagKind,
+    /// Byte offset of the `<` in the value.
+    pub offset: usize,
+    pub length: usize,
+}
+
+/// The tags in `value` in order of appearance. A `<` that does not start a tag, as in
+/// `a < b` or `<5 minutes`, is left as text.
+pub fn tags(value: &str) -> Vec<Tag> {
+    let mut tags = Vec::new();
+    let mut i = 0;
+
+    while let Some(start) = value[i..].find('<').map(|start| i + start) {
+        match parse_tag(&value[start..]) {
+            Some((name, kind, length)) => {
+                tags.push(Tag {
+                    name,
+                    kind,
+                    offset: start,
+                    length,
+                });
+                i = start + length;
+            }
+            None => i = start + 1,
+        }
+    }
+
+    tags
+}
+
+fn parse_tag(text: &str) -> Option<(String, TagKind, usize)> {
+    let rest = &text[1..];
+    let (closing, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    let length = rest
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':')))
+        .unwrap_or(rest.len());
+    let name = &rest[..length];
+    if name.is_empty() || !name.starts_with(|c: char| c.is_alphanumeric()) {
+        return None;
+    }
+
+    // Attributes run up to the closing `>`, which may be inside a quoted value.
+    let mut quote = None;
+    let mut end = None;
+    for (i, c) in rest[length..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '<') => return None,
+            (None, '>') => {
+                end = Some(length + i);
+                break;
+            }
+            (None, _) => {}
+        }
+    }
+
+    let end = end?;
+    let attributes = &rest[length..end];
+    if !attributes.is_empty() && !attributes.starts_with(|c: char| c.is_whitespace() || c == '/') {
+        return None;
+    }
+
+    let kind = if closing {
+        TagKind::Close
+    } else if attributes.trim_end().ends_with('/')
+        || VOID_ELEMENTS.contains(&name.to_lowercase().as_str())
+    {
+        TagKind::SelfClosing
+    } else {
+        TagKind::Open
+    };
+
+    let prefix = if closing { 2 } else { 1 };
+    Some((name.to_string(), kind, prefix + end + 1))
+}
+
+/// Checks that the tags of `tags` are balanced and properly nested, returning the offending
+/// tag and a description of the problem.
+fn unbalanced(tags: &[Tag]) -> Option<(&Tag, String)> {
+    let mut open: Vec<&Tag> = Vec::new();
+
+    for tag in tags {
+        match tag.kind {
+            TagKind::Open => open.push(tag),
+            TagKind::SelfClosing => {}
+            TagKind::Close => match open.pop() {
+                Some(opening) if opening.name == tag.name => {}
+                Some(opening) => {
+                    return Some((tag, format!("</{}> closes <{}>", tag.name, opening.name)));
+                }
+                None => {
+                    return Some((tag, format!("</{}> has no opening tag", tag.name)));
+                }
+            },
+        }
+    }
+
+    open.first()
+        .map(|tag| (*tag, format!("<{}> is not closed", tag.name)))
+}
+
+/// Checks that the tags in a value are balanced and properly nested.
+pub fn check(locale: &LocaleFile, translation: &Translation) -> Option<Issue> {
+    let tags = tags(translation.value);
+    let (tag, problem) = unbalanced(&tags)?;
+
+    Some(issue(
+        IssueKind::InvalidMarkup,
+        locale,
+        translation.key,
+        format!(
+            "{} has invalid markup in '{}': {}",
+            file_name(&locale.file),
+            translation.key,
+            problem
+        ),
+        value_location(locale, translation, tag.offset, tag.length),
+    ))
+}
+
+/// Checks that a translation uses the same tags as the default locale. Attributes are not
+/// compared, as a link may point to a localized page. Values with invalid markup are left
+/// to `check`.
+pub fn check_tags(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+) -> Option<Issue> {
+    let default_tags = tags(default.value);
+    let translation_tags = tags(translation.value);
+    if unbalanced(&default_tags).is_some() || unbalanced(&translation_tags).is_some() {
+        return None;
+    }
+
+    let expected = opening_tags(&default_tags);
+    let actual = opening_tags(&translation_tags);
+
+    let mut differences = Vec::new();
+    for (tags, other, label) in [
+        (&expected, &actual, "missing"),
+        (&actual, &expected, "unexpected"),
+    ] {
+        let mut names = Vec::new();
+        for name in tags {
+            let count = |tags: &Vec<String>| tags.iter().filter(|tag| *tag == name).count();
+            if count(tags) > count(other) && !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+
+        if !names.is_empty() {
+            differences.push(format!("{} {}", label, names.join(", ")));
+        }
+    }
+
+    if differences.is_empty() {
+        return None;
+    }
+
+    Some(value_issue(
+        IssueKind::MarkupMismatch,
+        default_locale,
+        locale,
+        default,
+        translation,
+        format!(
+            "{} has tags in '{}' that differ from {}: {}",
+            file_name(&locale.file),
+            translation.key,
+            file_name(&default_locale.file),
+            differences.join("; ")
+        ),
+    ))
+}
+
+/// The opening and self-closing tags, as `<name>` or `<name/>`.
+fn opening_tags(tags: &[Tag]) -> Vec<String> {
+    tags.iter()
+        .filter_map(|tag| match tag.kind {
+            TagKind::Open => Some(format!("<{}>", tag.name)),
+            TagKind::SelfClosing => Some(format!("<{}/>", tag.name)),
+            TagKind::Close => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+
+    fn summary(value: &str) -> Vec<(String, TagKind, usize, usize)> {
+        tags(value)
+            .into_iter()
+            .map(|tag| (tag.name, tag.kind, tag.offset, tag.length))
+            .collect()
+    }
+
+    fn check_value(value: &str) -> Option<Issue> {
+        let locale = locale_file("en", &format!("{{a: {:?}}}", value));
+        check(&locale, &translation(&locale, "a"))
+    }
+
+    fn check_values(default: &str, value: &str) -> Option<Issue> {
+        let en = locale_file("en", &format!("{{a: {:?}}}", default));
+        let fr = locale_file("fr", &format!("{{a: {:?}}}", value));
+
+        check_tags(&en, &fr, &translation(&en, "a"), &translation(&fr, "a"))
+    }
+
+    #[test]
+    fn reads_tags() {
+        assert_eq!(
+            summary("<a href=\"/x?a>b\">link</a><br><1/>"),
+            [
+                ("a".to_string(), TagKind::Open, 0, 17),
+                ("a".to_string(), TagKind::Close, 21, 4),
+                ("br".to_string(), TagKind::SelfClosing, 25, 4),
+                ("1".to_string(), TagKind::SelfClosing, 29, 4),
+            ]
+        );
+        assert!(summary("a < b, <5 minutes, <-> and <b").is_empty());
+    }
+
+    #[test]
+    fn reports_unbalanced_tags() {
+        assert!(check_value("<b>bold <i>both</i></b>").is_none());
+
+        let cases = [
+            ("<b>bold", "<b> is not closed"),
+            ("bold</b>", "</b> has no opening tag"),
+            ("<b><i>both</b></i>", "</b> closes <i>"),
+        ];
+        for (value, problem) in cases {
+            let issue = check_value(value).unwrap();
+            assert_eq!(issue.kind, IssueKind::InvalidMarkup);
+            assert!(issue.diagnostic.message.ends_with(problem), "{}", value);
+        }
+    }
+
+    #[test]
+    fn compares_tags_to_the_default_locale() {
+        assert!(
+            check_values(
+                "Read the <a href=\"/terms\">terms</a><br/>",
+                "<br/>Lisez les <a href=\"/fr/conditions\">conditions</a>"
+            )
+            .is_none()
+        );
+
+        let issue = check_values("<b>Hi</b> <i>there</i>", "<b>Salut</b> <u>toi</u>").unwrap();
+        assert_eq!(issue.kind, IssueKind::MarkupMismatch);
+        assert!(
+            issue
+                .diagnostic
+                .message
+                .ends_with("missing <i>; unexpected <u>")
+        );
+
+        let issue = check_values("<0/> and <0/>", "<0/>").unwrap();
+        assert!(issue.diagnostic.message.ends_with("missing <0/>"));
+    }
+
+    #[test]
+    fn leaves_invalid_markup_to_check() {
+        assert!(check_values("<b>Hi</b>", "<b>Salut").is_none());
+    }
+}
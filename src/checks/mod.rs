@@ -0,0 +1,361 @@
+use crate::config::Config;
+use crate::diagnostics::{Diagnostic, Location};
+use crate::document::{Node, Span};
+use crate::plurals;
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use icu::MessageFormat;
+use placeholders::PlaceholderSyntax;
+
+pub mod consistency;
+pub mod icu;
+pub mod length;
+pub mod markup;
+pub mod placeholders;
+pub mod typography;
+pub mod unicode;
+pub mod untranslated;
+
+/// A string value in a locale file.
+pub struct Translation<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+    pub span: Span,
+}
+
+/// Runs the checks on the values of `locale`. Checks that compare values to the default
+/// locale are skipped for the default locale itself.
+pub fn check_locale(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    config: &Config,
+) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let icu = config.message_format == MessageFormat::Icu;
+
+    for translation in translations(locale) {
+        issues.extend(markup::check(locale, &translation));
+        issues.extend(length::check(
+            default_locale,
+            locale,
+            &translation,
+            &config.length_limits,
+        ));
+        issues.extend(length::check_marker(locale, &translation));
+        issues.extend(typography::check(
+            locale,
+            &translation,
+            &config.typography_rules,
+        ));
+        issues.extend(unicode::check(locale, &translation));
+
+        if icu {
+            issues.extend(icu::check(locale, &translation));
+        }
+    }
+
+    if locale.file == default_locale.file {
+        return issues;
+    }
+
+    // ICU arguments are compared by the ICU check, which also compares their types.
+    let syntaxes = config
+        .placeholder_syntaxes
+        .iter()
+        .filter(|syntax| !icu || **syntax != PlaceholderSyntax::Brace)
+        .copied()
+        .collect::<Vec<_>>();
+
+    for (default, translation) in translation_pairs(default_locale, locale) {
+        issues.extend(placeholders::check(
+            default_locale,
+            locale,
+            &default,
+            &translation,
+            &syntaxes,
+        ));
+
+        issues.extend(markup::check_tags(
+            default_locale,
+            locale,
+            &default,
+            &translation,
+        ));
+
+        issues.extend(consistency::check(
+            default_locale,
+            locale,
+            &default,
+            &translation,
+            &config.consistency_checks,
+        ));
+
+        issues.extend(untranslated::check(
+            default_locale,
+            locale,
+            &default,
+            &translation,
+            &config.untranslated_values,
+        ));
+
+        issues.extend(length::check_expansion(
+            default_locale,
+            locale,
+            &default,
+            &translation,
+            &config.length_limits,
+        ));
+
+        if icu {
+            issues.extend(icu::check_arguments(
+                default_locale,
+                locale,
+                &default,
+                &translation,
+            ));
+        }
+    }
+
+    issues
+}
+
+/// The string values of `locale`, in the order of its keys.
+pub fn translations(locale: &LocaleFile) -> Vec<Translation<'_>> {
+    locale
+        .keys
+        .iter()
+        .filter_map(|key| match &locale.document.find(key)?.value {
+            Node::String { value, span } => Some(Translation {
+                key,
+                value,
+                span: *span,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The values of `locale` together with the value of the same key in the default locale.
+/// Plural forms the default locale does not have are paired with its `other` form. Empty
+/// values are left out, as there is nothing to compare.
+fn translation_pairs<'a>(
+    default_locale: &'a LocaleFile,
+    locale: &'a LocaleFile,
+) -> Vec<(Translation<'a>, Translation<'a>)> {
+    let defaults = translations(default_locale);
+    let groups = plurals::plural_groups(default_locale);
+
+    translations(locale)
+        .into_iter()
+        .filter(|translation| !translation.value.is_empty())
+        .filter_map(|translation| {
+            let key = match plurals::plural_form(&groups, translation.key) {
+                Some((group, _)) if !default_locale.keys.iter().any(|k| k == translation.key) => {
+                    group.key("other")
+                }
+                _ => translation.key.to_string(),
+            };
+
+            let default = defaults
+                .iter()
+                .find(|default| default.key == key && !default.value.is_empty())?;
+
+            Some((
+                Translation {
+                    key: default.key,
+                    value: default.value,
+                    span: default.span,
+                },
+                translation,
+            ))
+        })
+        .collect()
+}
+
+/// An issue of `kind` with its default severity.
+pub fn issue(
+    kind: IssueKind,
+    locale: &LocaleFile,
+    key: &str,
+    message: String,
+    location: Location,
+) -> Issue {
+    Issue {
+        kind,
+        locale: locale.locale.clone(),
+        file: locale.file.clone(),
+        key: key.to_string(),
+        diagnostic: Diagnostic::new(kind.default_severity(), message, Some(location)),
+    }
+}
+
+/// An issue pointing at a translated value, with a note pointing at the default locale's value.
+pub fn value_issue(
+    kind: IssueKind,
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+    message: String,
+) -> Issue {
+    let mut issue = issue(
+        kind,
+        locale,
+        translation.key,
+        message,
+        Location::in_document(&locale.file, &locale.document, translation.span),
+    );
+
+    issue.diagnostic = issue.diagnostic.with_note(
+        &format!("value in {}", file_name(&default_locale.file)),
+        Location::in_document(&default_locale.file, &default_locale.document, default.span),
+    );
+
+    issue
+}
+
+/// The location of `length` bytes at `offset` in the value of `translation`.
+pub fn value_location(
+    locale: &LocaleFile,
+    translation: &Translation,
+    offset: usize,
+    length: usize,
+) -> Location {
+    let source = &locale.document.source;
+
+    Location::in_document(
+        &locale.file,
+        &locale.document,
+        Span {
+            start: source_offset(source, translation.span, offset),
+            end: source_offset(source, translation.span, offset + length),
+        },
+    )
+}
+
+/// Maps a byte offset in a string value to the offset in the source, taking the opening
+/// quote and escape sequences into account.
+fn source_offset(source: &str, span: Span, offset: usize) -> usize {
+    let mut chars = source[span.start..span.end]
+        .char_indices()
+        .skip(1)
+        .peekable();
+    let mut decoded = 0;
+
+    while let Some((i, c)) = chars.next() {
+        if decoded >= offset {
+            return span.start + i;
+        }
+
+        if c != '\\' {
+            decoded += c.len_utf8();
+            continue;
+        }
+
+        decoded += match chars.next().map(|(_, c)| c) {
+            Some('u') => match read_hex(&mut chars, 4) {
+                // A high surrogate is followed by an escaped low surrogate, together one character.
+                0xD800..=0xDBFF => {
+                    chars.by_ref().take(6).for_each(drop);
+                    4
+                }
+                code => char::from_u32(code).map_or(3, char::len_utf8),
+            },
+            Some('x') => char::from_u32(read_hex(&mut chars, 2)).map_or(1, char::len_utf8),
+            // JSON5 line continuations do not end up in the value.
+            Some('\r') => {
+                chars.next_if(|(_, c)| *c == '\n');
+                0
+            }
+            Some('\n' | '\u{2028}' | '\u{2029}') => 0,
+            Some('b' | 'f' | 'n' | 'r' | 't' | 'v' | '0') => 1,
+            Some(c) => c.len_utf8(),
+            None => 0,
+        };
+    }
+
+    span.end
+}
+
+fn read_hex(chars: &mut impl Iterator<Item = (usize, char)>, digits: usize) -> u32 {
+    let hex = chars.take(digits).map(|(_, c)| c).collect::<String>();
+    u32::from_str_radix(&hex, 16).unwrap_or(0)
+}
+
+/// Helpers for the tests of the checks.
+#[cfg(test)]
+pub mod testing {
+    use super::{Translation, translations};
+    use crate::document::{Document, Syntax};
+    use crate::parser::{self, ObjectKeyOption};
+    use crate::validation::LocaleFile;
+    use std::path::PathBuf;
+
+    /// A locale file named after `locale`, parsed from JSON5 `source`.
+    pub fn locale_file(locale: &str, source: &str) -> LocaleFile {
+        let document = Document::parse(source.to_string(), Syntax::Json5).unwrap();
+        let keys = parser::get_translation_keys(
+            document.to_value(),
+            "".to_string(),
+            ObjectKeyOption::ExcludeObjectKeys,
+        )
+        .unwrap();
+
+        LocaleFile {
+            locale: locale.to_string(),
+            file: PathBuf::from(format!("{}.json5", locale)),
+            document,
+            keys,
+        }
+    }
+
+    /// The value of `key` in `locale`.
+    pub fn translation<'a>(locale: &'a LocaleFile, key: &str) -> Translation<'a> {
+        translations(locale)
+            .into_iter()
+            .find(|translation| translation.key == key)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use testing::{locale_file, translation};
+
+    /// The source text at the position of byte `offset` of the value of `a`.
+    fn source_at(source: &str, offset: usize) -> String {
+        let locale = locale_file("en", source);
+        let translation = translation(&locale, "a");
+        let start = source_offset(source, translation.span, offset);
+
+        source[start..].chars().take(2).collect()
+    }
+
+    #[test]
+    fn maps_value_offsets_to_the_source() {
+        assert_eq!(source_at("{a: 'abcd'}", 2), "cd");
+        assert_eq!(source_at("{a: 'a\\tbc'}", 2), "bc");
+        assert_eq!(source_at("{a: 'a\\u00e9bc'}", 3), "bc");
+        assert_eq!(source_at("{a: 'a\\xe9bc'}", 3), "bc");
+        assert_eq!(source_at("{a: '\\ud83d\\ude00bc'}", 4), "bc");
+        assert_eq!(source_at("{a: 'a\\'bc'}", 2), "bc");
+    }
+
+    #[test]
+    fn skips_line_continuations() {
+        assert_eq!(source_at("{a: 'ab\\\ncd'}", 3), "d'");
+        assert_eq!(source_at("{a: 'ab\\\r\ncd'}", 3), "d'");
+        assert_eq!(source_at("{a: 'ab\\\rcd'}", 3), "d'");
+        assert_eq!(source_at("{a: 'ab\\\u{2028}cd'}", 3), "d'");
+    }
+
+    #[test]
+    fn locates_values_in_crlf_files() {
+        let source = "{\r\n  a: 'one \\\r\ntwo  three'\r\n}";
+        let locale = locale_file("en", source);
+        let translation = translation(&locale, "a");
+        let location = value_location(&locale, &translation, 7, 2);
+
+        assert_eq!((location.line, location.column), (3, 4));
+    }
+}
@@ -0,0 +1,374 @@
+use crate::checks::{Translation, value_issue};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceholderSyntax {
+    // {name}
+    Brace,
+    // {{name}}
+    DoubleBrace,
+    // %s, %d, %1$s
+    Printf,
+    // :name
+    Colon,
+    // %{name}
+    PercentBrace,
+}
+
+impl PlaceholderSyntax {
+    // Longer syntaxes go first, so `{{name}}` is not read as `{name}` and `%{name}` not as `%`.
+    const PRECEDENCE: [PlaceholderSyntax; 5] = [
+        PlaceholderSyntax::DoubleBrace,
+        PlaceholderSyntax::PercentBrace,
+        PlaceholderSyntax::Brace,
+        PlaceholderSyntax::Printf,
+        PlaceholderSyntax::Colon,
+    ];
+
+    /// Reads a placeholder at the start of `text`, returning it in a normalized form and the
+    /// number of bytes it takes up.
+    fn parse(&self, text: &str, previous: Option<char>) -> Option<(String, usize)> {
+        match self {
+            PlaceholderSyntax::DoubleBrace => {
+                let inner = &text.strip_prefix("{{")?[..text[2..].find("}}")?];
+                if inner.contains(['{', '}']) {
+                    return None;
+                }
+
+                // i18next allows a format after a comma and a '-' to skip escaping.
+                let name = inner.split(',').next().unwrap().trim();
+                let name = name.strip_prefix('-').unwrap_or(name).trim();
+                is_name(name).then(|| (format!("{{{{{}}}}}", name), inner.len() + 4))
+            }
+            PlaceholderSyntax::PercentBrace => {
+                let inner = &text.strip_prefix("%{")?[..text[2..].find('}')?];
+                is_name(inner).then(|| (format!("%{{{}}}", inner), inner.len() + 3))
+            }
+            PlaceholderSyntax::Brace => {
+                let inner = &text.strip_prefix('{')?[..text[1..].find('}')?];
+                is_name(inner).then(|| (format!("{{{}}}", inner), inner.len() + 2))
+            }
+            PlaceholderSyntax::Printf => parse_printf(text),
+            PlaceholderSyntax::Colon => {
+                if previous.is_some_and(|c| c.is_alphanumeric() || c == ':') {
+                    return None;
+                }
+
+                let name = text.strip_prefix(':')?;
+                let length = name
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(name.len());
+                let name = &name[..length];
+
+                name.starts_with(|c: char| c.is_ascii_alphabetic())
+                    .then(|| (format!(":{}", name), length + 1))
+            }
+        }
+    }
+}
+
+/// A placeholder in its normalized form, together with the syntax it was read in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placeholder {
+    pub syntax: PlaceholderSyntax,
+    pub text: String,
+}
+
+impl Placeholder {
+    /// Whether this is a printf placeholder without a position, such as `%s`, which is
+    /// filled in order.
+    fn is_sequential(&self) -> bool {
+        self.syntax == PlaceholderSyntax::Printf && !self.text.contains('$')
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-' | '$'))
+}
+
+/// Reads a printf conversion such as `%s`, `%05.2f` or `%1$s`. Flags, width and precision
+/// are left out of the normalized form, as translations may change them.
+fn parse_printf(text: &str) -> Option<(String, usize)> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'%') {
+        return None;
+    }
+
+    let mut i = 1;
+    let digits = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let mut position = None;
+    let count = digits(i);
+    if count > 0 && bytes.get(i + count) == Some(&b'$') {
+        position = Some(&text[i..i + count]);
+        i += count + 1;
+    }
+
+    // A space is not accepted as a flag, so "50% off" is not read as "% o".
+    i += bytes[i..]
+        .iter()
+        .take_while(|b| matches!(b, b'-' | b'+' | b'0' | b'#'))
+        .count();
+    i += digits(i);
+    if bytes.get(i) == Some(&b'.') {
+        i += 1 + digits(i + 1);
+    }
+    i += bytes[i..]
+        .iter()
+        .take_while(|b| matches!(b, b'h' | b'l' | b'L' | b'q' | b'j' | b'z' | b't'))
+        .count();
+
+    let conversion = match *bytes.get(i)? {
+        b'i' => 'd',
+        c @ (b's' | b'd' | b'u' | b'f' | b'F' | b'e' | b'E' | b'g' | b'G' | b'x' | b'X' | b'o'
+        | b'c' | b'p' | b'@') => c as char,
+        _ => return None,
+    };
+
+    let placeholder = match position {
+        Some(position) => format!("%{}${}", position, conversion),
+        None => format!("%{}", conversion),
+    };
+
+    Some((placeholder, i + 1))
+}
+
+/// The placeholders in `value` in order of appearance, in the given syntaxes.
+pub fn placeholders(value: &str, syntaxes: &[PlaceholderSyntax]) -> Vec<Placeholder> {
+    let mut placeholders = Vec::new();
+    let mut previous = None;
+    let mut i = 0;
+
+    while i < value.len() {
+        let text = &value[i..];
+
+        if syntaxes.contains(&PlaceholderSyntax::Printf) && text.starts_with("%%") {
+            previous = Some('%');
+            i += 2;
+            continue;
+        }
+
+        let placeholder = PlaceholderSyntax::PRECEDENCE
+            .iter()
+            .filter(|syntax| syntaxes.contains(syntax))
+            .find_map(|syntax| Some((*syntax, syntax.parse(text, previous)?)));
+
+        match placeholder {
+            Some((syntax, (text, length))) => {
+                placeholders.push(Placeholder { syntax, text });
+                i += length;
+                previous = value[..i].chars().next_back();
+            }
+            None => {
+                let c = text.chars().next().unwrap();
+                previous = Some(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+
+    placeholders
+}
+
+pub fn check(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+    syntaxes: &[PlaceholderSyntax],
+) -> Option<Issue> {
+    let texts = |value| {
+        placeholders(value, syntaxes)
+            .into_iter()
+            .map(|placeholder| placeholder.text)
+            .collect::<Vec<_>>()
+    };
+    let expected = texts(default.value);
+    let actual = texts(translation.value);
+
+    let missing = difference(&expected, &actual);
+    let unexpected = difference(&actual, &expected);
+
+    let message = if !missing.is_empty() || !unexpected.is_empty() {
+        let mut differences = Vec::new();
+        if !missing.is_empty() {
+            differences.push(format!("missing {}", join(&missing)));
+        }
+        if !unexpected.is_empty() {
+            differences.push(format!("unexpected {}", join(&unexpected)));
+        }
+
+        format!(
+            "{} has placeholders in '{}' that differ from {}: {}",
+            file_name(&locale.file),
+            translation.key,
+            file_name(&default_locale.file),
+            differences.join("; ")
+        )
+    } else {
+        // Printf placeholders without a position are filled in order, so they cannot be
+        // reordered to fit the grammar of the translation.
+        let sequential = |value| {
+            placeholders(value, syntaxes)
+                .into_iter()
+                .filter(Placeholder::is_sequential)
+                .map(|placeholder| placeholder.text)
+                .collect::<Vec<_>>()
+        };
+
+        let expected = sequential(default.value);
+        let actual = sequential(translation.value);
+        if expected == actual {
+            return None;
+        }
+
+        format!(
+            "{} has printf placeholders in '{}' in a different order than {} ({} instead of {}), \
+             use positional placeholders such as %1$s to reorder them",
+            file_name(&locale.file),
+            translation.key,
+            file_name(&default_locale.file),
+            actual.join(" "),
+            expected.join(" ")
+        )
+    };
+
+    Some(value_issue(
+        IssueKind::PlaceholderMismatch,
+        default_locale,
+        locale,
+        default,
+        translation,
+        message,
+    ))
+}
+
+/// The placeholders of `a` that are not in `b`, without duplicates.
+fn difference<'a>(a: &'a [String], b: &[String]) -> Vec<&'a String> {
+    let mut difference = Vec::new();
+    for placeholder in a {
+        if !b.contains(placeholder) && !difference.contains(&placeholder) {
+            difference.push(placeholder);
+        }
+    }
+
+    difference
+}
+
+fn join(placeholders: &[&String]) -> String {
+    placeholders
+        .iter()
+        .map(|placeholder| placeholder.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+
+    const ALL: [PlaceholderSyntax; 5] = PlaceholderSyntax::PRECEDENCE;
+
+    fn texts(value: &str, syntaxes: &[PlaceholderSyntax]) -> Vec<String> {
+        placeholders(value, syntaxes)
+            .into_iter()
+            .map(|placeholder| placeholder.text)
+            .collect()
+    }
+
+    fn check_values(default: &str, value: &str) -> Option<Issue> {
+        let en = locale_file("en", &format!("{{a: {:?}}}", default));
+        let fr = locale_file("fr", &format!("{{a: {:?}}}", value));
+
+        check(
+            &en,
+            &fr,
+            &translation(&en, "a"),
+            &translation(&fr, "a"),
+            &ALL,
+        )
+    }
+
+    #[test]
+    fn reads_brace_placeholders() {
+        let syntaxes = [PlaceholderSyntax::Brace];
+
+        assert_eq!(
+            texts("Hi {name}, {count}", &syntaxes),
+            ["{name}", "{count}"]
+        );
+        assert!(texts("{not a name} {}", &syntaxes).is_empty());
+    }
+
+    #[test]
+    fn reads_double_brace_placeholders() {
+        let syntaxes = [PlaceholderSyntax::DoubleBrace, PlaceholderSyntax::Brace];
+
+        assert_eq!(
+            texts("{{name}} {{- html}} {{date, short}}", &syntaxes),
+            ["{{name}}", "{{html}}", "{{date}}"]
+        );
+    }
+
+    #[test]
+    fn reads_printf_placeholders() {
+        let syntaxes = [PlaceholderSyntax::Printf];
+
+        assert_eq!(
+            texts("%s has %05.2f %1$s %i %ld", &syntaxes),
+            ["%s", "%f", "%1$s", "%d", "%d"]
+        );
+        assert!(texts("100%% sure, 50% off", &syntaxes).is_empty());
+    }
+
+    #[test]
+    fn reads_colon_placeholders() {
+        let syntaxes = [PlaceholderSyntax::Colon];
+
+        assert_eq!(
+            texts(":count items for :user_name", &syntaxes),
+            [":count", ":user_name"]
+        );
+        assert!(texts("at 10:30, see https://example.com", &syntaxes).is_empty());
+    }
+
+    #[test]
+    fn reads_percent_brace_placeholders() {
+        let syntaxes = [PlaceholderSyntax::PercentBrace, PlaceholderSyntax::Brace];
+
+        let placeholders = placeholders("%{first} and {second}", &syntaxes);
+        assert_eq!(placeholders[0].text, "%{first}");
+        assert_eq!(placeholders[0].syntax, PlaceholderSyntax::PercentBrace);
+        assert_eq!(placeholders[1].syntax, PlaceholderSyntax::Brace);
+    }
+
+    #[test]
+    fn reports_missing_and_unexpected_placeholders() {
+        let issue = check_values("Hello {name}", "Bonjour {nom}").unwrap();
+
+        assert_eq!(issue.kind, IssueKind::PlaceholderMismatch);
+        assert!(issue.diagnostic.message.contains("missing {name}"));
+        assert!(issue.diagnostic.message.contains("unexpected {nom}"));
+    }
+
+    #[test]
+    fn allows_reordering_named_placeholders() {
+        assert!(check_values("%{first} then %{second}", "%{second} puis %{first}").is_none());
+        assert!(check_values("{first} then {second}", "{second} puis {first}").is_none());
+        assert!(check_values("%1$s then %2$d", "%2$d puis %1$s").is_none());
+    }
+
+    #[test]
+    fn reports_reordered_sequential_printf_placeholders() {
+        let issue = check_values("%s has %d items", "%d articles pour %s").unwrap();
+
+        assert!(issue.diagnostic.message.contains("different order"));
+        assert!(check_values("%s has %d items", "%s a %d articles").is_none());
+    }
+}
@@ -0,0 +1,424 @@
+use crate::checks::markup;
+use crate::checks::{Translation, issue, value_location};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name, locale_language};
+use serde::{Deserialize, Serialize};
+
+const NBSP: &str = "\u{a0}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypographyRule {
+    // French: a non-breaking space before `: ; ! ?` and inside `« »`.
+    FrenchSpacing,
+    // Typographic quotes instead of straight quotes, such as „…“ in German.
+    Quotes,
+    // Spanish: questions and exclamations start with `¿` and `¡`.
+    InvertedPunctuation,
+}
+
+impl TypographyRule {
+    pub const ALL: [TypographyRule; 3] = [
+        TypographyRule::FrenchSpacing,
+        TypographyRule::Quotes,
+        TypographyRule::InvertedPunctuation,
+    ];
+}
+
+/// A change to a value to follow the typography rules of its locale.
+#[derive(Debug, Clone)]
+pub struct Correction {
+    /// Byte offset in the value.
+    pub offset: usize,
+    /// Number of bytes replaced, or 0 for an insertion.
+    pub length: usize,
+    pub replacement: String,
+    pub description: String,
+}
+
+fn region(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .skip(1)
+        .find(|part| part.len() == 2)
+        .unwrap_or_default()
+        .to_uppercase()
+}
+
+/// The opening and closing quotes of `locale`, if it has typographic quotes that differ
+/// from straight quotes.
+fn quotes(locale: &str) -> Option<(&'static str, &'static str)> {
+    let language = locale_language(locale);
+
+    Some(match language.as_str() {
+        "de" if region(locale) == "CH" => ("«", "»"),
+        "de" | "bg" | "cs" | "et" | "is" | "lt" | "sk" | "sl" => ("„", "“"),
+        "hr" | "hu" | "pl" | "ro" => ("„", "”"),
+        "fr" => ("«\u{a0}", "\u{a0}»"),
+        "be" | "ca" | "el" | "es" | "nb" | "no" | "ru" | "uk" => ("«", "»"),
+        "fi" | "sv" => ("”", "”"),
+        "ja" => ("「", "」"),
+        "zh" if matches!(region(locale).as_str(), "TW" | "HK") || locale.contains("Hant") => {
+            ("「", "」")
+        }
+        "zh" => ("“", "”"),
+        _ => return None,
+    })
+}
+
+/// Byte ranges of `value` that are left alone: tags, URLs, and placeholders or ICU
+/// arguments between braces.
+fn protected_ranges(value: &str) -> Vec<(usize, usize)> {
+    let mut ranges = markup::tags(value)
+        .into_iter()
+        .map(|tag| (tag.offset, tag.offset + tag.length))
+        .collect::<Vec<_>>();
+
+    for word in value.split_whitespace() {
+        if word.contains("://") || word.starts_with("www.") {
+            let start = word.as_ptr() as usize - value.as_ptr() as usize;
+            ranges.push((start, start + word.len()));
+        }
+    }
+
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '{' => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    ranges.push((start, i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ranges
+}
+
+/// The corrections `value` needs to follow the enabled typography rules of `locale`.
+pub fn corrections(locale: &str, value: &str, rules: &[TypographyRule]) -> Vec<Correction> {
+    let protected = protected_ranges(value);
+    let is_protected = |i: usize| {
+        protected
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&i))
+    };
+    let language = locale_language(locale);
+    let mut corrections = Vec::new();
+
+    if rules.contains(&TypographyRule::FrenchSpacing) && language == "fr" {
+        // Canadian French only puts a space before a colon.
+        let marks: &[char] = if region(locale) == "CA" {
+            &[':']
+        } else {
+            &[':', ';', '!', '?']
+        };
+
+        corrections.extend(french_spacing(value, marks, &is_protected));
+    }
+
+    if rules.contains(&TypographyRule::Quotes)
+        && let Some((open, close)) = quotes(locale)
+    {
+        let straight = value
+            .char_indices()
+            .filter(|(i, c)| *c == '"' && !is_protected(*i))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        // An unpaired quote is left alone, as it is unclear where the quotation ends.
+        for pair in straight.chunks_exact(2) {
+            for (offset, replacement) in [(pair[0], open), (pair[1], close)] {
+                corrections.push(Correction {
+                    offset,
+                    length: 1,
+                    replacement: replacement.to_string(),
+                    description: format!("straight quotes instead of {}…{}", open, close),
+                });
+            }
+        }
+    }
+
+    if rules.contains(&TypographyRule::InvertedPunctuation)
+        && matches!(language.as_str(), "es" | "gl" | "ast")
+    {
+        corrections.extend(inverted_punctuation(value, &is_protected));
+    }
+
+    corrections.sort_by_key(|correction| correction.offset);
+    corrections
+}
+
+fn french_spacing(
+    value: &str,
+    marks: &[char],
+    is_protected: &impl Fn(usize) -> bool,
+) -> Vec<Correction> {
+    let mut corrections = Vec::new();
+    let is_space = |c: char| c == '\u{a0}' || c == '\u{202f}';
+
+    for (i, c) in value.char_indices() {
+        if is_protected(i) {
+            continue;
+        }
+
+        let previous = value[..i].chars().next_back();
+        let next = value[i + c.len_utf8()..].chars().next();
+
+        if marks.contains(&c) {
+            // Marks followed by a letter or digit are not punctuation, as in URLs or `10:30`.
+            if next.is_some_and(char::is_alphanumeric) {
+                continue;
+            }
+
+            match previous {
+                None => {}
+                Some(p) if is_space(p) || matches!(p, '!' | '?' | ':' | ';' | '.') => {}
+                Some(' ') => corrections.push(Correction {
+                    offset: i - 1,
+                    length: 1,
+                    replacement: NBSP.to_string(),
+                    description: format!("a regular space before '{}'", c),
+                }),
+                Some(_) => corrections.push(Correction {
+                    offset: i,
+                    length: 0,
+                    replacement: NBSP.to_string(),
+                    description: format!("no non-breaking space before '{}'", c),
+                }),
+            }
+        } else if c == '«' {
+            match next {
+                Some(n) if is_space(n) => {}
+                Some(' ') => corrections.push(Correction {
+                    offset: i + c.len_utf8(),
+                    length: 1,
+                    replacement: NBSP.to_string(),
+                    description: "a regular space after '«'".to_string(),
+                }),
+                Some(_) => corrections.push(Correction {
+                    offset: i + c.len_utf8(),
+                    length: 0,
+                    replacement: NBSP.to_string(),
+                    description: "no non-breaking space after '«'".to_string(),
+                }),
+                None => {}
+            }
+        } else if c == '»' {
+            match previous {
+                Some(p) if is_space(p) => {}
+                Some(' ') => corrections.push(Correction {
+                    offset: i - 1,
+                    length: 1,
+                    replacement: NBSP.to_string(),
+                    description: "a regular space before '»'".to_string(),
+                }),
+                Some(_) => corrections.push(Correction {
+                    offset: i,
+                    length: 0,
+                    replacement: NBSP.to_string(),
+                    description: "no non-breaking space before '»'".to_string(),
+                }),
+                None => {}
+            }
+        }
+    }
+
+    corrections
+}
+
+fn inverted_punctuation(value: &str, is_protected: &impl Fn(usize) -> bool) -> Vec<Correction> {
+    let mut corrections = Vec::new();
+    let mut sentence_start = None;
+
+    for (i, c) in value.char_indices() {
+        if sentence_start.is_none() && !c.is_whitespace() && !"\"'«“„(".contains(c) {
+            sentence_start = Some(i);
+        }
+
+        let Some(start) = sentence_start else {
+            continue;
+        };
+
+        if is_protected(i) {
+            continue;
+        }
+
+        // Marks followed by a letter or digit do not end a sentence, as in `3.5`.
+        if value[i + c.len_utf8()..].starts_with(char::is_alphanumeric) {
+            continue;
+        }
+
+        let opening = match c {
+            '?' => '¿',
+            '!' => '¡',
+            '.' | '…' => {
+                sentence_start = None;
+                continue;
+            }
+            _ => continue,
+        };
+
+        // The second mark of `?!` or `!!` closes the same sentence.
+        if !value[..i].ends_with(['?', '!']) && !value[start..i].contains(opening) {
+            corrections.push(Correction {
+                offset: start,
+                length: 0,
+                replacement: opening.to_string(),
+                description: format!("no '{}' opening a sentence ending with '{}'", opening, c),
+            });
+        }
+
+        if !value[i + 1..].starts_with(['?', '!']) {
+            sentence_start = None;
+        }
+    }
+
+    corrections
+}
+
+/// Applies `corrections`, which are sorted by offset, to `value`.
+pub fn fix(value: &str, corrections: &[Correction]) -> String {
+    let mut fixed = String::new();
+    let mut position = 0;
+
+    for correction in corrections {
+        if correction.offset < position {
+            continue;
+        }
+
+        fixed.push_str(&value[position..correction.offset]);
+        fixed.push_str(&correction.replacement);
+        position = correction.offset + correction.length;
+    }
+
+    fixed.push_str(&value[position..]);
+    fixed
+}
+
+/// Checks that a value follows the typography rules of its locale.
+pub fn check(
+    locale: &LocaleFile,
+    translation: &Translation,
+    rules: &[TypographyRule],
+) -> Option<Issue> {
+    let corrections = corrections(&locale.locale, translation.value, rules);
+    let first = corrections.first()?;
+
+    let mut descriptions = Vec::new();
+    for correction in &corrections {
+        if !descriptions.contains(&correction.description) {
+            descriptions.push(correction.description.clone());
+        }
+    }
+
+    Some(issue(
+        IssueKind::Typography,
+        locale,
+        translation.key,
+        format!(
+            "{} does not follow the typography of {} in '{}': {}",
+            file_name(&locale.file),
+            locale.locale,
+            translation.key,
+            descriptions.join("; ")
+        ),
+        value_location(locale, translation, first.offset, first.length.max(1)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::diagnostics::Severity;
+
+    fn fixed(locale: &str, value: &str) -> String {
+        fix(value, &corrections(locale, value, &TypographyRule::ALL))
+    }
+
+    #[test]
+    fn fixes_french_spacing() {
+        assert_eq!(
+            fixed(
+                "fr",
+                "Attention : fichier ouvert! Voulez-vous « continuer»?"
+            ),
+            "Attention\u{a0}: fichier ouvert\u{a0}! Voulez-vous «\u{a0}continuer\u{a0}»\u{a0}?"
+        );
+        assert_eq!(
+            fixed("fr", "À 10:30, voir https://example.com?a=1"),
+            "À 10:30, voir https://example.com?a=1"
+        );
+        assert_eq!(fixed("fr", "Vraiment\u{202f}?!"), "Vraiment\u{202f}?!");
+    }
+
+    #[test]
+    fn fixes_canadian_french_spacing_before_colons_only() {
+        assert_eq!(fixed("fr-CA", "Nom: Jean! Prêt?"), "Nom\u{a0}: Jean! Prêt?");
+    }
+
+    #[test]
+    fn fixes_quotes() {
+        assert_eq!(fixed("de", "Klicke \"Speichern\""), "Klicke „Speichern“");
+        assert_eq!(fixed("de-CH", "Klicke \"Speichern\""), "Klicke «Speichern»");
+        assert_eq!(
+            fixed("fr", "Cliquez \"Enregistrer\""),
+            "Cliquez «\u{a0}Enregistrer\u{a0}»"
+        );
+        assert_eq!(fixed("de", "5\" Bildschirm"), "5\" Bildschirm");
+        assert_eq!(
+            fixed("de", "<a href=\"/x\">Link</a> {name}"),
+            "<a href=\"/x\">Link</a> {name}"
+        );
+        assert_eq!(fixed("en", "Click \"Save\""), "Click \"Save\"");
+    }
+
+    #[test]
+    fn fixes_spanish_inverted_punctuation() {
+        assert_eq!(
+            fixed("es", "Hola. Quieres guardar? Hecho!"),
+            "Hola. ¿Quieres guardar? ¡Hecho!"
+        );
+        assert_eq!(fixed("es", "De verdad?!"), "¿De verdad?!");
+        assert_eq!(
+            fixed("es", "¿Guardar? Versión 3.5!"),
+            "¿Guardar? ¡Versión 3.5!"
+        );
+    }
+
+    #[test]
+    fn runs_only_the_enabled_rules() {
+        let value = "Cliquez \"Enregistrer\" ici!";
+        let rules = [TypographyRule::Quotes];
+
+        assert_eq!(
+            fix(value, &corrections("fr", value, &rules)),
+            "Cliquez «\u{a0}Enregistrer\u{a0}» ici!"
+        );
+        assert!(corrections("fr", value, &[]).is_empty());
+    }
+
+    #[test]
+    fn reports_each_problem_once() {
+        let fr = locale_file("fr", "{a: 'Oui? Non?'}");
+
+        let issue = check(&fr, &translation(&fr, "a"), &TypographyRule::ALL).unwrap();
+        assert_eq!(issue.kind, IssueKind::Typography);
+        assert_eq!(issue.severity(), Severity::Warning);
+        assert!(
+            issue
+                .diagnostic
+                .message
+                .ends_with(": no non-breaking space before '?'")
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::checks::{Translation, issue, value_location};
+use crate::diagnostics::Location;
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use unicode_normalization::is_nfc;
+
+/// The name of an invisible character that does not belong in a value, often pasted from a
+/// word processor. Zero width joiners are only invisible characters between ASCII, as
+/// emoji sequences and several scripts need them.
+fn invisible_character(value: &str, i: usize, c: char) -> Option<&'static str> {
+    let name = match c {
+        '\u{200B}' => "zero width space",
+        '\u{2060}' => "word joiner",
+        '\u{FEFF}' => "byte order mark",
+        '\u{180E}' => "Mongolian vowel separator",
+        '\u{200C}' => "zero width non-joiner",
+        '\u{200D}' => "zero width joiner",
+        _ => return None,
+    };
+
+    if matches!(c, '\u{200C}' | '\u{200D}') {
+        let previous = value[..i].chars().next_back();
+        let next = value[i + c.len_utf8()..].chars().next();
+        if !previous.is_none_or(|c| c.is_ascii()) || !next.is_none_or(|c| c.is_ascii()) {
+            return None;
+        }
+    }
+
+    Some(name)
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_right_to_left(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFE}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    )
+}
+
+/// The offset of the first bidi control character that does not belong in `value`. Text
+/// without right-to-left characters needs none of them, and otherwise every embedding,
+/// override or isolate has to be closed.
+fn stray_bidi_control(value: &str) -> Option<(usize, char)> {
+    if !value.chars().any(is_right_to_left) {
+        return value.char_indices().find(|(_, c)| is_bidi_control(*c));
+    }
+
+    let mut open = Vec::new();
+    for (i, c) in value.char_indices() {
+        match c {
+            '\u{202A}' | '\u{202B}' | '\u{202D}' | '\u{202E}' => open.push((i, c, '\u{202C}')),
+            '\u{2066}'..='\u{2068}' => open.push((i, c, '\u{2069}')),
+            '\u{202C}' | '\u{2069}' => match open.pop() {
+                Some((_, _, close)) if close == c => {}
+                _ => return Some((i, c)),
+            },
+            _ => {}
+        }
+    }
+
+    open.first().map(|(i, c, _)| (*i, *c))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Script> {
+        match c {
+            'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' => {
+                Some(Script::Latin)
+            }
+            '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Some(Script::Greek),
+            '\u{0400}'..='\u{052F}' => Some(Script::Cyrillic),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Script::Latin => "Latin",
+            Script::Greek => "Greek",
+            Script::Cyrillic => "Cyrillic",
+        }
+    }
+}
+
+/// The first word of `value` with letters from more than one of the Latin, Greek and
+/// Cyrillic scripts, which look alike, such as "Pаssword" with a Cyrillic `а`.
+fn mixed_script_word(value: &str) -> Option<(usize, &str, Vec<Script>)> {
+    for word in value.split(|c: char| !c.is_alphanumeric()) {
+        let mut scripts = Vec::new();
+        for script in word.chars().filter_map(Script::of) {
+            if !scripts.contains(&script) {
+                scripts.push(script);
+            }
+        }
+
+        if scripts.len() > 1 {
+            let offset = word.as_ptr() as usize - value.as_ptr() as usize;
+            return Some((offset, word, scripts));
+        }
+    }
+
+    None
+}
+
+/// Checks a value for invisible characters, stray bidi control characters, replacement
+/// characters, letters from scripts that look alike and text that is not in NFC form.
+pub fn check(locale: &LocaleFile, translation: &Translation) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let file = file_name(&locale.file);
+    let key = translation.key;
+    let value = translation.value;
+
+    let invisible = value
+        .char_indices()
+        .find_map(|(i, c)| Some((i, c, invisible_character(value, i, c)?)));
+    if let Some((i, c, name)) = invisible {
+        issues.push(issue(
+            IssueKind::InvisibleCharacter,
+            locale,
+            key,
+            format!(
+                "{} has an invisible {} (U+{:04X}) in '{}'",
+                file, name, c as u32, key
+            ),
+            value_location(locale, translation, i, c.len_utf8()),
+        ));
+    }
+
+    if let Some((i, c)) = stray_bidi_control(value) {
+        issues.push(issue(
+            IssueKind::BidiControl,
+            locale,
+            key,
+            format!(
+                "{} has a stray bidi control character (U+{:04X}) in '{}'",
+                file, c as u32, key
+            ),
+            value_location(locale, translation, i, c.len_utf8()),
+        ));
+    }
+
+    if let Some(i) = value.find('\u{FFFD}') {
+        issues.push(issue(
+            IssueKind::ReplacementCharacter,
+            locale,
+            key,
+            format!(
+                "{} has a replacement character (U+FFFD) in '{}', the value was likely decoded \
+                 with the wrong encoding",
+                file, key
+            ),
+            value_location(locale, translation, i, '\u{FFFD}'.len_utf8()),
+        ));
+    }
+
+    if let Some((i, word, scripts)) = mixed_script_word(value) {
+        let scripts = scripts
+            .iter()
+            .map(Script::name)
+            .collect::<Vec<_>>()
+            .join(" and ");
+
+        issues.push(issue(
+            IssueKind::MixedScripts,
+            locale,
+            key,
+            format!(
+                "{} has a word mixing {} letters in '{}': {}",
+                file, scripts, key, word
+            ),
+            value_location(locale, translation, i, word.len()),
+        ));
+    }
+
+    if !is_nfc(value) {
+        issues.push(issue(
+            IssueKind::NotNfc,
+            locale,
+            key,
+            format!(
+                "{} has a value that is not in NFC form in '{}', such as a letter followed by \
+                 a combining accent instead of the accented letter",
+                file, key
+            ),
+            Location::in_document(&locale.file, &locale.document, translation.span),
+        ));
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use serde_json::json;
+
+    fn check_value(value: &str) -> Vec<Issue> {
+        let locale = locale_file("en", &json!({ "a": value }).to_string());
+        check(&locale, &translation(&locale, "a"))
+    }
+
+    fn kinds(value: &str) -> Vec<IssueKind> {
+        check_value(value).iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn accepts_clean_values() {
+        assert!(kinds("Save changes").is_empty());
+        assert!(kinds("Сохранить изменения").is_empty());
+        assert!(kinds("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467} family").is_empty());
+        assert!(kinds("می\u{200c}خواهم").is_empty());
+        assert!(kinds("Name: \u{2067}مرحبا\u{2069}").is_empty());
+    }
+
+    #[test]
+    fn reports_invisible_characters() {
+        let issues = check_value("Pass\u{200b}word");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::InvisibleCharacter);
+        assert!(
+            issues[0]
+                .diagnostic
+                .message
+                .contains("zero width space (U+200B)")
+        );
+
+        assert_eq!(kinds("a\u{200d}b"), [IssueKind::InvisibleCharacter]);
+        assert_eq!(kinds("\u{feff}Hello"), [IssueKind::InvisibleCharacter]);
+    }
+
+    #[test]
+    fn reports_stray_bidi_controls() {
+        assert_eq!(kinds("Hello\u{200f}"), [IssueKind::BidiControl]);
+        assert_eq!(kinds("\u{202b}مرحبا"), [IssueKind::BidiControl]);
+        assert_eq!(kinds("مرحبا\u{2069}"), [IssueKind::BidiControl]);
+    }
+
+    #[test]
+    fn reports_replacement_characters() {
+        assert_eq!(kinds("Caf\u{fffd}"), [IssueKind::ReplacementCharacter]);
+    }
+
+    #[test]
+    fn reports_words_mixing_scripts() {
+        let issues = check_value("Enter your P\u{430}ssword");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::MixedScripts);
+        assert!(
+            issues[0]
+                .diagnostic
+                .message
+                .ends_with("mixing Latin and Cyrillic letters in 'a': P\u{430}ssword")
+        );
+
+        assert!(kinds("iPhone и Android").is_empty());
+    }
+
+    #[test]
+    fn reports_values_not_in_nfc() {
+        assert_eq!(kinds("Cafe\u{301}"), [IssueKind::NotNfc]);
+        assert!(kinds("Caf\u{e9}").is_empty());
+    }
+}
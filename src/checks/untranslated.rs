@@ -0,0 +1,142 @@
+use crate::checks::{Translation, value_issue};
+use crate::suppression::glob_match;
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name, locale_language};
+use serde::{Deserialize, Serialize};
+
+/// Settings for the check for values that are identical to the default locale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UntranslatedValues {
+    // Values shorter than this, in characters, are not checked.
+    pub min_length: usize,
+    // Globs of keys whose values may be the same in every locale, such as brand names.
+    pub allowed_keys: Vec<String>,
+    // Values that may be the same in every locale, such as "OK".
+    pub allowed_values: Vec<String>,
+}
+
+impl Default for UntranslatedValues {
+    fn default() -> Self {
+        Self {
+            min_length: 3,
+            allowed_keys: Vec::new(),
+            allowed_values: Vec::new(),
+        }
+    }
+}
+
+/// Checks that a translation is not copied from the default locale. Values without letters,
+/// such as numbers, and locales of the same language as the default locale are skipped.
+pub fn check(
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+    default: &Translation,
+    translation: &Translation,
+    settings: &UntranslatedValues,
+) -> Option<Issue> {
+    let value = translation.value.trim();
+
+    if translation.value != default.value
+        || value.chars().count() < settings.min_length
+        || !value.chars().any(char::is_alphabetic)
+        || locale_language(&locale.locale) == locale_language(&default_locale.locale)
+        || settings
+            .allowed_values
+            .iter()
+            .any(|allowed| allowed == value)
+        || settings
+            .allowed_keys
+            .iter()
+            .any(|pattern| glob_match(pattern, translation.key))
+    {
+        return None;
+    }
+
+    Some(value_issue(
+        IssueKind::UntranslatedValue,
+        default_locale,
+        locale,
+        default,
+        translation,
+        format!(
+            "{} has the same value in '{}' as {}, it may not be translated",
+            file_name(&locale.file),
+            translation.key,
+            file_name(&default_locale.file)
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::diagnostics::Severity;
+    use serde_json::json;
+
+    fn check_value(locale: &str, key: &str, value: &str, settings: &UntranslatedValues) -> bool {
+        let source = key
+            .rsplit('.')
+            .fold(json!(value), |value, name| json!({ name: value }))
+            .to_string();
+        let en = locale_file("en", &source);
+        let other = locale_file(locale, &source);
+
+        check(
+            &en,
+            &other,
+            &translation(&en, key),
+            &translation(&other, key),
+            settings,
+        )
+        .is_some()
+    }
+
+    #[test]
+    fn reports_copied_values_as_warnings() {
+        let en = locale_file("en", "{a: 'Save changes'}");
+        let de = locale_file("de", "{a: 'Save changes'}");
+
+        let issue = check(
+            &en,
+            &de,
+            &translation(&en, "a"),
+            &translation(&de, "a"),
+            &UntranslatedValues::default(),
+        )
+        .unwrap();
+        assert_eq!(issue.kind, IssueKind::UntranslatedValue);
+        assert_eq!(issue.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn skips_short_values_and_values_without_letters() {
+        let settings = UntranslatedValues::default();
+
+        assert!(!check_value("de", "a", "OK", &settings));
+        assert!(!check_value("de", "a", "  Hi  ", &settings));
+        assert!(!check_value("de", "a", "1,234.5", &settings));
+        assert!(check_value("de", "a", "Fax", &settings));
+    }
+
+    #[test]
+    fn skips_locales_of_the_default_language() {
+        let settings = UntranslatedValues::default();
+
+        assert!(!check_value("en-GB", "a", "Save changes", &settings));
+        assert!(!check_value("en_US", "a", "Save changes", &settings));
+    }
+
+    #[test]
+    fn skips_allowed_keys_and_values() {
+        let settings = UntranslatedValues {
+            min_length: 3,
+            allowed_keys: vec!["brand.*".to_string()],
+            allowed_values: vec!["Dashboard".to_string()],
+        };
+
+        assert!(!check_value("de", "brand.name", "Acme Cloud", &settings));
+        assert!(!check_value("de", "nav.home", "Dashboard", &settings));
+        assert!(check_value("de", "nav.home", "Acme Cloud", &settings));
+    }
+}
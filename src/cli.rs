@@ -1,9 +1,9 @@
 use crate::commands::Commands;
 use camino::Utf8PathBuf;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use inquire::autocompletion::Replacement;
 use inquire::validator::{ErrorMessage, StringValidator, Validation};
-use inquire::{Autocomplete, CustomUserError, Text};
+use inquire::{Autocomplete, CustomUserError, InquireError, Text};
 use std::path::PathBuf;
 
 #[derive(Parser, Clone)]
@@ -16,13 +16,24 @@ pub struct Cli {
     #[clap(short, long)]
     pub translations_dir: Option<Utf8PathBuf>,
 
+    #[clap(short, long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
 pub struct CommandArgs {
     pub cli_args: Cli,
     pub translations_directory: PathBuf,
+    pub backup_directory: PathBuf,
+    pub baseline_file: PathBuf,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -100,18 +111,12 @@ pub fn prompt_translation_key(
     translation_keys: Vec<String>,
     invert_validator: bool,
     prompt_text: &str,
-) -> Result<String, String> {
-    let key = Text::new(prompt_text)
+) -> Result<String, InquireError> {
+    Text::new(prompt_text)
         .with_autocomplete(UpdateAutocomplete::new(translation_keys.clone()))
         .with_validator(TranslationKeyValidator::new(
             translation_keys,
             invert_validator,
         ))
-        .prompt();
-
-    if key.is_err() {
-        return Err(format!("Could not select key: {:?}", key.unwrap_err()));
-    }
-
-    Ok(key.unwrap())
+        .prompt()
 }
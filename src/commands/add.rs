@@ -1,70 +1,67 @@
 use crate::cli::CommandArgs;
 use crate::commands::CommandError;
 use crate::parser::ObjectKeyOption;
-use crate::{cli, config, files, parser};
+use crate::validation::file_name;
+use crate::{backup, cli, config, files, parser};
 use inquire::Text;
 
 pub fn add_command(args: CommandArgs, key: Option<String>) -> Result<(), CommandError> {
     let (default_locale_path, translation_files) =
-        files::get_translation_files(args.translations_directory.clone())
-            .map_err(|e| CommandError::Generic(e.to_string()))?;
+        files::get_translation_files(args.translations_directory.clone())?;
 
-    let translation_value = parser::get_parsed_translation_file(default_locale_path.into())
-        .map_err(|e| CommandError::Generic(e.to_string()))?;
+    let translation_value = parser::get_parsed_translation_file(default_locale_path)?;
 
     let translation_keys = parser::get_translation_keys(
         translation_value.clone(),
         "".to_string(),
         ObjectKeyOption::OnlyObjectKeys,
-    )
-    .map_err(|e| CommandError::Generic(e.to_string()))?;
+    )?;
 
     // this could be improved but I can't be bothered
-    let key = if key.is_some() {
-        key.unwrap()
+    let key = if let Some(key) = key {
+        key
     } else {
-        cli::prompt_translation_key(translation_keys.clone(), true, "Translation key to add:")
-            .map_err(|e| CommandError::Generic(e.to_string()))?
+        cli::prompt_translation_key(translation_keys.clone(), true, "Translation key to add:")?
     };
 
     if translation_keys.contains(&key) {
-        return Err(CommandError::Generic(format!(
+        return Err(CommandError::InvalidInput(format!(
             "'{key}' has nested keys. Could not add as this key."
         )));
     }
 
+    let mut values = Vec::new();
     for file_path in &translation_files {
-        let translation_value = parser::get_parsed_translation_file(file_path.into())
-            .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-        let initial_value = Text::new(
-            format!(
-                "Value for {}:",
-                file_path.file_name().unwrap().to_str().unwrap().to_string()
-            )
-            .as_str(),
-        )
-        .prompt();
-
-        if initial_value.is_err() {
-            return Err(CommandError::Generic(format!(
-                "Could not update value: {:?}",
-                initial_value.unwrap_err()
-            )));
-        }
+        let initial_value =
+            Text::new(format!("Value for {}:", file_name(file_path)).as_str()).prompt()?;
 
-        let initial_value = initial_value.unwrap();
         if initial_value.is_empty() {
             // skip writing empty values
             continue;
         }
 
-        let translation_value =
-            parser::update_translation_key(translation_value, key.clone(), initial_value)
-                .map_err(|e| CommandError::Generic(e.to_string()))?;
+        values.push((file_path.clone(), initial_value));
+    }
+
+    // Taken after the prompts, so cancelling them does not leave a snapshot behind.
+    let changed_files = values
+        .iter()
+        .map(|(file_path, _)| file_path.clone())
+        .collect::<Vec<_>>();
+    backup::create_snapshot(
+        &args.backup_directory,
+        &format!("add {}", key),
+        &changed_files,
+    )
+    .map_err(CommandError::Io)?;
+
+    for (file_path, initial_value) in values {
+        let document = parser::get_translation_document(file_path.clone())?;
 
-        if let Err(err) = files::write_translation_file(file_path.into(), translation_value) {
-            return Err(CommandError::Generic(format!(
+        if let Err(err) =
+            files::write_translation_key(file_path, document, key.clone(), initial_value)
+        {
+            return Err(CommandError::Io(format!(
                 "Could not write translation file: {}",
                 err
             )));
@@ -0,0 +1,70 @@
+use crate::cli::CommandArgs;
+use crate::commands::CommandError;
+use crate::config::{CONFIG, Config};
+use crate::reports::badge;
+use crate::validation::{self, ValidationOptions};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn badges_command(args: CommandArgs, out_dir: PathBuf) -> Result<(), CommandError> {
+    let config = CONFIG.get().unwrap();
+    let validation = validation::validate(
+        args.translations_directory.clone(),
+        &ValidationOptions::default(),
+    )?;
+
+    fs::create_dir_all(&out_dir).map_err(|err| {
+        CommandError::Io(format!(
+            "Could not create directory {}: {}",
+            out_dir.display(),
+            err
+        ))
+    })?;
+
+    let mut translated = 0;
+    let mut total = 0;
+
+    for locale in &validation.locales {
+        let coverage = validation.coverage(locale);
+        write_badge(
+            &out_dir,
+            &locale.locale,
+            &locale.locale,
+            coverage.percentage(),
+            config,
+        )?;
+
+        // The default locale is always complete, so it would only skew the overall number.
+        if locale.file != validation.default_locale.file {
+            translated += coverage.translated;
+            total += coverage.total;
+        }
+    }
+
+    let overall = if total == 0 {
+        100.0
+    } else {
+        translated as f64 * 100.0 / total as f64
+    };
+    write_badge(&out_dir, "overall", "translations", overall, config)?;
+
+    println!("Badges written to {}", out_dir.display());
+
+    Ok(())
+}
+
+fn write_badge(
+    out_dir: &Path,
+    name: &str,
+    label: &str,
+    percentage: f64,
+    config: &Config,
+) -> Result<(), CommandError> {
+    // Round down, so a nearly complete locale does not show up as 100%.
+    let message = format!("{}%", percentage.floor());
+    let color = badge::coverage_color(percentage, &config.badge_thresholds);
+    let path = out_dir.join(format!("{}.svg", name));
+
+    fs::write(&path, badge::badge(label, &message, color))
+        .map_err(|err| CommandError::Io(format!("Could not write {}: {}", path.display(), err)))
+}
@@ -0,0 +1,72 @@
+use crate::checks::typography;
+use crate::cli::CommandArgs;
+use crate::commands::CommandError;
+use crate::config::CONFIG;
+use crate::validation::file_name;
+use crate::{backup, checks, config, files, parser, validation};
+use std::path::PathBuf;
+
+pub fn fix_command(args: CommandArgs, dry_run: bool) -> Result<(), CommandError> {
+    let (_, translation_files) = files::get_translation_files(args.translations_directory)?;
+    let rules = &CONFIG.get().unwrap().typography_rules;
+
+    let mut fixes: Vec<(PathBuf, Vec<(String, String)>)> = Vec::new();
+    for file_path in translation_files {
+        let locale_file = validation::get_locale_file(file_path.clone())?;
+
+        let values = checks::translations(&locale_file)
+            .into_iter()
+            .filter_map(|translation| {
+                let corrections =
+                    typography::corrections(&locale_file.locale, translation.value, rules);
+                if corrections.is_empty() {
+                    return None;
+                }
+
+                let fixed = typography::fix(translation.value, &corrections);
+                println!(
+                    "{}: '{}': {:?} -> {:?}",
+                    file_name(&file_path),
+                    translation.key,
+                    translation.value,
+                    fixed
+                );
+                Some((translation.key.to_string(), fixed))
+            })
+            .collect::<Vec<_>>();
+
+        if !values.is_empty() {
+            fixes.push((file_path, values));
+        }
+    }
+
+    if fixes.is_empty() {
+        println!("Nothing to fix.");
+        return Ok(());
+    }
+
+    let count = fixes.iter().map(|(_, values)| values.len()).sum::<usize>();
+    if dry_run {
+        println!("Would fix {} values in {} files.", count, fixes.len());
+        return Ok(());
+    }
+
+    let files = fixes
+        .iter()
+        .map(|(file, _)| file.clone())
+        .collect::<Vec<_>>();
+    backup::create_snapshot(&args.backup_directory, "fix", &files).map_err(CommandError::Io)?;
+
+    for (file_path, values) in fixes {
+        let document = parser::get_translation_document(file_path.clone())?;
+
+        files::write_translation_keys(file_path, document, values).map_err(|err| {
+            CommandError::Io(format!("Could not write translation file: {}", err))
+        })?;
+    }
+
+    println!("Fixed {} values in {} files.", count, files.len());
+    config::run_after_write_commands();
+
+    Ok(())
+}
@@ -1,9 +1,19 @@
-use clap::ArgAction;
 use crate::cli::CommandArgs;
+use crate::diagnostics::{Diagnostic, Location, Severity};
+use crate::parser::ParserError;
+use crate::reports::ReportTarget;
+use crate::validation::ValidationOptions;
+use clap::ArgAction;
 use clap::Subcommand;
+use inquire::InquireError;
 use std::fmt::Display;
+use std::path::PathBuf;
 
 mod add;
+mod badges;
+mod fix;
+mod report;
+mod restore;
 mod update;
 mod validate;
 
@@ -17,17 +27,138 @@ pub enum Commands {
     Validate {
         #[arg(long, action=ArgAction::SetTrue)]
         fail_on_empty: Option<bool>,
+        #[arg(long, action=ArgAction::SetTrue)]
+        fail_on_duplicates: Option<bool>,
+        #[arg(long, value_name = "FORMAT[=PATH]")]
+        report: Vec<ReportTarget>,
+        #[arg(long, action=ArgAction::SetTrue)]
+        no_ci_annotations: Option<bool>,
+        #[arg(long, action=ArgAction::SetTrue)]
+        baseline: Option<bool>,
+        #[arg(long, action=ArgAction::SetTrue, conflicts_with_all = ["baseline", "since"])]
+        write_baseline: Option<bool>,
+        #[arg(long, value_name = "REF")]
+        since: Option<String>,
+    },
+    #[clap(about = "Generate SVG coverage badges for every locale")]
+    Badges { out_dir: PathBuf },
+    #[clap(about = "Generate a translation status report")]
+    Report {
+        #[arg(long, value_name = "DIR")]
+        html: PathBuf,
+    },
+    #[clap(about = "Fix the typography of values according to their locale")]
+    Fix {
+        #[arg(long, action=ArgAction::SetTrue)]
+        dry_run: Option<bool>,
+    },
+    #[clap(about = "Restore locale files from a backup")]
+    Restore {
+        snapshot: Option<String>,
+        files: Vec<String>,
+        #[arg(long, action=ArgAction::SetTrue)]
+        list: Option<bool>,
     },
 }
 
+/// Errors that stop a command. Every kind of error has its own exit code, so scripts can tell
+/// them apart.
+#[derive(Debug)]
 pub enum CommandError {
-    Generic(String),
+    /// Validation found issues with severity error.
+    ValidationFailed(usize),
+    /// The arguments refer to something that does not exist, such as a key or backup.
+    InvalidInput(String),
+    /// The config file or a file referenced by it is missing or invalid.
+    Config(String),
+    /// A file or directory could not be read or written.
+    Io(String),
+    /// A locale file could not be parsed.
+    Parse(Box<Diagnostic>),
+    /// An interactive prompt was cancelled.
+    Cancelled,
+}
+
+impl CommandError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandError::ValidationFailed(_) => 1,
+            // Clap also uses 2 for invalid arguments.
+            CommandError::InvalidInput(_) => 2,
+            CommandError::Config(_) => 3,
+            CommandError::Io(_) => 4,
+            CommandError::Parse(_) => 5,
+            CommandError::Cancelled => 130,
+        }
+    }
+
+    /// A name for the kind of error, used in JSON output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CommandError::ValidationFailed(_) => "validation_failed",
+            CommandError::InvalidInput(_) => "invalid_input",
+            CommandError::Config(_) => "config",
+            CommandError::Io(_) => "io",
+            CommandError::Parse(_) => "parse",
+            CommandError::Cancelled => "cancelled",
+        }
+    }
+
+    /// The message without a source snippet.
+    pub fn message(&self) -> String {
+        match self {
+            CommandError::Parse(diagnostic) => diagnostic.message.clone(),
+            err => err.to_string(),
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            CommandError::Parse(diagnostic) => diagnostic.location.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 impl Display for CommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CommandError::Generic(msg) => write!(f, "{}", msg),
+            CommandError::ValidationFailed(count) => write!(f, "{} issues found.", count),
+            CommandError::InvalidInput(msg) | CommandError::Config(msg) | CommandError::Io(msg) => {
+                write!(f, "{}", msg)
+            }
+            CommandError::Parse(diagnostic) => write!(f, "{}", diagnostic),
+            CommandError::Cancelled => write!(f, "Cancelled."),
+        }
+    }
+}
+
+impl From<ParserError> for CommandError {
+    fn from(err: ParserError) -> Self {
+        match err {
+            ParserError::FileDoesNotExist(_)
+            | ParserError::CouldNotOpenFile(_)
+            | ParserError::InvalidFileName(_)
+            | ParserError::CouldNotReadDirectory { .. } => CommandError::Io(err.to_string()),
+            ParserError::CouldNotParseFile(diagnostic) => CommandError::Parse(diagnostic),
+            ParserError::InvalidValueType { .. } => CommandError::Parse(Box::new(Diagnostic::new(
+                Severity::Error,
+                err.to_string(),
+                None,
+            ))),
+            ParserError::DefaultLocaleNotFound { .. } => CommandError::Config(err.to_string()),
+            ParserError::Generic(_) => CommandError::InvalidInput(err.to_string()),
+        }
+    }
+}
+
+impl From<InquireError> for CommandError {
+    fn from(err: InquireError) -> Self {
+        match err {
+            InquireError::OperationCanceled | InquireError::OperationInterrupted => {
+                CommandError::Cancelled
+            }
+            err => CommandError::Io(format!("Could not read input: {}", err)),
         }
     }
 }
@@ -36,8 +167,33 @@ pub fn handle_command(command: Commands, args: CommandArgs) -> Result<(), Comman
     match command {
         Commands::Update { key } => update::update_command(args, key),
         Commands::Add { key } => add::add_command(args, key),
-        Commands::Validate { fail_on_empty } => {
-            validate::validate_command(args, fail_on_empty.unwrap_or(false))
-        }
+        Commands::Validate {
+            fail_on_empty,
+            fail_on_duplicates,
+            report,
+            no_ci_annotations,
+            baseline,
+            write_baseline,
+            since,
+        } => validate::validate_command(
+            args,
+            ValidationOptions {
+                fail_on_empty: fail_on_empty.unwrap_or(false),
+                fail_on_duplicates: fail_on_duplicates.unwrap_or(false),
+            },
+            report,
+            !no_ci_annotations.unwrap_or(false),
+            baseline.unwrap_or(false),
+            write_baseline.unwrap_or(false),
+            since,
+        ),
+        Commands::Badges { out_dir } => badges::badges_command(args, out_dir),
+        Commands::Report { html } => report::report_command(args, html),
+        Commands::Fix { dry_run } => fix::fix_command(args, dry_run.unwrap_or(false)),
+        Commands::Restore {
+            snapshot,
+            files,
+            list,
+        } => restore::restore_command(args, snapshot, files, list.unwrap_or(false)),
     }
 }
@@ -0,0 +1,29 @@
+use crate::cli::CommandArgs;
+use crate::commands::CommandError;
+use crate::reports;
+use crate::validation::{self, ValidationOptions};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn report_command(args: CommandArgs, html_directory: PathBuf) -> Result<(), CommandError> {
+    let validation = validation::validate(
+        args.translations_directory.clone(),
+        &ValidationOptions::default(),
+    )?;
+
+    fs::create_dir_all(&html_directory).map_err(|err| {
+        CommandError::Io(format!(
+            "Could not create directory {}: {}",
+            html_directory.display(),
+            err
+        ))
+    })?;
+
+    let path = html_directory.join("index.html");
+    fs::write(&path, reports::html::validation_report(&validation))
+        .map_err(|err| CommandError::Io(format!("Could not write {}: {}", path.display(), err)))?;
+
+    println!("Report written to {}", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::backup::Snapshot;
+use crate::cli::{CommandArgs, OutputFormat};
+use crate::commands::CommandError;
+use crate::{backup, config, reports};
+use inquire::{MultiSelect, Select};
+use std::fs;
+
+pub fn restore_command(
+    args: CommandArgs,
+    snapshot: Option<String>,
+    files: Vec<String>,
+    list: bool,
+) -> Result<(), CommandError> {
+    let snapshots = backup::get_snapshots(&args.backup_directory).map_err(CommandError::Io)?;
+
+    if snapshots.is_empty() {
+        return Err(CommandError::InvalidInput(format!(
+            "No backups found in {}",
+            args.backup_directory.display()
+        )));
+    }
+
+    if list {
+        match args.cli_args.output {
+            OutputFormat::Text => {
+                for snapshot in &snapshots {
+                    println!("{}", describe_snapshot(snapshot));
+                }
+            }
+            OutputFormat::Json => println!("{}", reports::json::snapshots_report(&snapshots)),
+        }
+
+        return Ok(());
+    }
+
+    let snapshot = match snapshot {
+        Some(id) => snapshots
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or_else(|| CommandError::InvalidInput(format!("Backup '{}' does not exist", id)))?,
+        None => {
+            let options = snapshots.iter().map(describe_snapshot).collect::<Vec<_>>();
+            let selected = Select::new("Backup to restore:", options.clone()).raw_prompt()?;
+
+            snapshots.into_iter().nth(selected.index).unwrap()
+        }
+    };
+
+    let files = if files.is_empty() {
+        MultiSelect::new("Files to restore:", snapshot.files.clone())
+            .with_all_selected_by_default()
+            .prompt()?
+    } else {
+        files
+    };
+
+    if let Some(file) = files.iter().find(|f| !snapshot.files.contains(f)) {
+        return Err(CommandError::InvalidInput(format!(
+            "Backup '{}' does not contain '{}'",
+            snapshot.id, file
+        )));
+    }
+
+    // Read the backed up contents first, as taking a new snapshot may rotate this one out.
+    let mut contents = Vec::new();
+    for file in &files {
+        let content = fs::read(snapshot.file_path(file)).map_err(|err| {
+            CommandError::Io(format!("Could not read {} from backup: {}", file, err))
+        })?;
+        contents.push((file, content));
+    }
+
+    let targets = files
+        .iter()
+        .map(|f| args.translations_directory.join(f))
+        .filter(|p| p.exists())
+        .collect::<Vec<_>>();
+
+    backup::create_snapshot(
+        &args.backup_directory,
+        &format!("restore {}", snapshot.id),
+        &targets,
+    )
+    .map_err(CommandError::Io)?;
+
+    for (file, content) in contents {
+        fs::write(args.translations_directory.join(file), content)
+            .map_err(|err| CommandError::Io(format!("Could not restore {}: {}", file, err)))?;
+
+        println!("Restored {} from {}", file, snapshot.id);
+    }
+
+    config::run_after_write_commands();
+
+    Ok(())
+}
+
+fn describe_snapshot(snapshot: &Snapshot) -> String {
+    format!(
+        "{}  {} UTC  {}  ({})",
+        snapshot.id,
+        backup::format_timestamp(snapshot.created_at, "-", " ", ":"),
+        snapshot.command,
+        snapshot.files.join(", ")
+    )
+}
@@ -1,84 +1,143 @@
-use serde_json::Value;
-use crate::cli::CommandArgs;
+use crate::baseline::{self, Baseline, BaselineComparison};
+use crate::cli::{CommandArgs, OutputFormat};
 use crate::commands::CommandError;
-use crate::parser::ObjectKeyOption;
-use crate::{files, parser};
-
-pub fn validate_command(args: CommandArgs, fail_on_empty: bool) -> Result<(), CommandError> {
-    let (default_locale_path, translation_files) =
-        files::get_translation_files(args.translations_directory.clone())
-            .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-    let default_translation_value = parser::get_parsed_translation_file(default_locale_path.into())
-        .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-    let default_translation_keys = parser::get_translation_keys(
-        default_translation_value.clone(),
-        "".to_string(),
-        ObjectKeyOption::ExcludeObjectKeys,
-    )
-    .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-    let mut issues: Vec<String> = Vec::new();
-
-    for file_path in translation_files {
-        let file_name = file_path.file_name().unwrap().to_str().unwrap().to_string();
-        let translation_value = parser::get_parsed_translation_file(file_path.into())
-            .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-        let translation_keys = parser::get_translation_keys(
-            translation_value.clone(),
-            "".to_string(),
-            ObjectKeyOption::ExcludeObjectKeys,
-        )
-        .map_err(|e| CommandError::Generic(e.to_string()))?;
-
-        for key in default_translation_keys.clone() {
-            if !translation_keys.contains(&key) {
-                issues.push(format!("{} is missing key '{}'", file_name, key));
-            }
-        }
+use crate::diagnostics::{Diagnostic, Severity, display_path};
+use crate::document::{Document, Syntax};
+use crate::git;
+use crate::reports;
+use crate::reports::ReportTarget;
+use crate::validation::{self, ValidationOptions};
+
+pub fn validate_command(
+    args: CommandArgs,
+    options: ValidationOptions,
+    mut reports: Vec<ReportTarget>,
+    ci_annotations: bool,
+    baseline: bool,
+    write_baseline: bool,
+    since: Option<String>,
+) -> Result<(), CommandError> {
+    // Stdout is reserved for the JSON document, so reports need a file to be written to.
+    if args.cli_args.output == OutputFormat::Json
+        && let Some(report) = reports.iter().find(|report| report.path.is_none())
+    {
+        return Err(CommandError::InvalidInput(format!(
+            "The {} report needs a path with --output json, such as --report {}=<path>",
+            report.format.name(),
+            report.format.name()
+        )));
+    }
+
+    let mut validation = validation::validate(args.translations_directory.clone(), &options)?;
 
-        if fail_on_empty {
-            let empty_values = get_empty_values(file_name, &translation_value, "".to_string());
-            issues.extend(empty_values.clone());
+    if write_baseline {
+        let baseline = Baseline::from_validation(&validation);
+        baseline::write_baseline(&args.baseline_file, &baseline).map_err(CommandError::Io)?;
+
+        match args.cli_args.output {
+            OutputFormat::Text => println!(
+                "Wrote {} issues to {}",
+                baseline.issues.len(),
+                display_path(&args.baseline_file)
+            ),
+            OutputFormat::Json => println!("{}", reports::json::validation_report(&validation)),
         }
+
+        return Ok(());
+    }
+
+    if baseline {
+        baseline::read_baseline(&args.baseline_file)
+            .map_err(CommandError::Config)?
+            .apply(&mut validation);
     }
-    
-    let issues = issues.iter();
-    let issue_count = issues.len();
-    
-    if issue_count > 0 {
-        for issue in issues {
-            eprintln!("{}", issue);
+
+    if let Some(reference) = &since {
+        let default_file = validation.default_locale.file.clone();
+        let base = git::read_file_at(&default_file, reference)
+            .map_err(CommandError::InvalidInput)?
+            .map(|source| {
+                Document::parse(source, Syntax::from_path(&default_file)).map_err(|err| {
+                    CommandError::Parse(Box::new(Diagnostic::new(
+                        Severity::Error,
+                        format!(
+                            "Could not parse {} at {}: {}",
+                            validation::file_name(&default_file),
+                            reference,
+                            err.message
+                        ),
+                        None,
+                    )))
+                })
+            })
+            .transpose()?;
+
+        let changed = validation.retain_changed(base.as_ref())?;
+
+        if args.cli_args.output == OutputFormat::Text {
+            eprintln!(
+                "Only reporting issues for {} keys added or changed since {}.\n",
+                changed, reference
+            );
         }
-        
-        return Err(CommandError::Generic(format!("{} issues found.", issue_count)));
     }
-    
-    println!("All keys present!");
 
-    Ok(())
-}
+    if ci_annotations {
+        // Annotations are printed to stdout, which is reserved for the document in JSON mode.
+        let stdout_available = args.cli_args.output == OutputFormat::Text;
+        reports.extend(reports::detect_ci_reports(&reports, stdout_available));
+    }
+
+    for report in &reports {
+        reports::write_report(report, &validation).map_err(CommandError::Io)?;
+    }
 
-fn get_empty_values(file_name: String, value: &Value, key: String) -> Vec<String> {
-    let mut issues: Vec<String> = Vec::new();
+    let error_count = validation.error_count();
 
-    match value {
-        Value::String(val) => {
-            if val.is_empty() {
-                issues.push(format!("{} has an empty value '{}'", file_name, key));
+    match args.cli_args.output {
+        OutputFormat::Text => {
+            for issue in &validation.issues {
+                eprintln!("{}\n", issue.diagnostic);
             }
-        },
-        Value::Object(val) => {
-            for (key, value) in val {
-                issues.extend(
-                    get_empty_values(file_name.clone(), value, key.to_owned())
-                );
+
+            if !validation.suppressed.is_empty() {
+                eprintln!("{} issues suppressed.", validation.suppressed.len());
+            }
+
+            if let Some(comparison) = &validation.baseline {
+                print_baseline_comparison(comparison);
+            }
+
+            if error_count == 0 {
+                println!("All keys present!");
             }
-        },
-        _ => ()
+        }
+        OutputFormat::Json => println!("{}", reports::json::validation_report(&validation)),
+    }
+
+    if error_count > 0 {
+        return Err(CommandError::ValidationFailed(error_count));
     }
 
-    issues
-}
\ No newline at end of file
+    Ok(())
+}
+
+fn print_baseline_comparison(comparison: &BaselineComparison) {
+    if !comparison.known.is_empty() {
+        eprintln!(
+            "{} known issues from the baseline were not reported.",
+            comparison.known.len()
+        );
+    }
+
+    if !comparison.fixed.is_empty() {
+        eprintln!(
+            "{} issues in the baseline are fixed, run 'validate --write-baseline' to remove them:",
+            comparison.fixed.len()
+        );
+
+        for entry in &comparison.fixed {
+            eprintln!("  {} {} {}", entry.kind.name(), entry.locale, entry.key);
+        }
+    }
+}
@@ -1,4 +1,12 @@
+use crate::checks::consistency::ConsistencyCheck;
+use crate::checks::icu::MessageFormat;
+use crate::checks::length::LengthLimits;
+use crate::checks::placeholders::PlaceholderSyntax;
+use crate::checks::typography::TypographyRule;
+use crate::checks::untranslated::UntranslatedValues;
+use crate::suppression::Suppression;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env::current_dir;
 use std::fs::File;
 use std::io::Read;
@@ -9,12 +17,45 @@ use std::sync::OnceLock;
 
 pub static CONFIG: OnceLock<Config> = OnceLock::new();
 
+pub const DEFAULT_CONFIG_PATH: &str = "tt.config.json";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
     pub post_write_commands: Vec<String>,
     pub default_locale: String,
     pub translations_directory: String,
+    pub backup_directory: String,
+    pub backup_count: usize,
+    pub baseline_file: String,
+    pub badge_thresholds: Vec<BadgeThreshold>,
+    pub locale_requirements: BTreeMap<String, LocaleRequirement>,
+    pub suppressions: Vec<Suppression>,
+    pub placeholder_syntaxes: Vec<PlaceholderSyntax>,
+    pub message_format: MessageFormat,
+    pub consistency_checks: Vec<ConsistencyCheck>,
+    pub untranslated_values: UntranslatedValues,
+    pub length_limits: LengthLimits,
+    pub typography_rules: Vec<TypographyRule>,
+    pub normalize_on_write: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocaleRequirement {
+    // Every issue fails validation, this is the default.
+    Required,
+    // Issues are reported as warnings and never fail validation.
+    Optional,
+    // Missing, empty and untranslated values are warnings, as long as the locale is
+    // translated for at least the given percentage.
+    MinCoverage(f64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BadgeThreshold {
+    pub min: f64,
+    pub color: String,
 }
 
 impl Default for Config {
@@ -23,60 +64,101 @@ impl Default for Config {
             default_locale: "en".to_string(),
             post_write_commands: Vec::new(),
             translations_directory: "translations".to_string(),
+            backup_directory: ".translate-tool/backups".to_string(),
+            backup_count: 10,
+            baseline_file: "tt.baseline.json".to_string(),
+            badge_thresholds: vec![
+                BadgeThreshold {
+                    min: 100.0,
+                    color: "#4c1".to_string(),
+                },
+                BadgeThreshold {
+                    min: 90.0,
+                    color: "#97ca00".to_string(),
+                },
+                BadgeThreshold {
+                    min: 75.0,
+                    color: "#dfb317".to_string(),
+                },
+                BadgeThreshold {
+                    min: 50.0,
+                    color: "#fe7d37".to_string(),
+                },
+                BadgeThreshold {
+                    min: 0.0,
+                    color: "#e05d44".to_string(),
+                },
+            ],
+            locale_requirements: BTreeMap::new(),
+            suppressions: Vec::new(),
+            placeholder_syntaxes: vec![PlaceholderSyntax::Brace, PlaceholderSyntax::DoubleBrace],
+            message_format: MessageFormat::Plain,
+            consistency_checks: ConsistencyCheck::ALL.to_vec(),
+            untranslated_values: UntranslatedValues::default(),
+            length_limits: LengthLimits::default(),
+            typography_rules: TypographyRule::ALL.to_vec(),
+            normalize_on_write: false,
         }
     }
 }
 
-fn load_config(config_path: Option<String>) -> Result<Config, String> {
-    let config_path = config_path.unwrap_or_else(|| "tt.config.json".to_string());
+/// Loads the config file, or returns `None` if no path is given and there is no config file
+/// in the working directory.
+fn load_config(config_path: Option<String>) -> Result<Option<Config>, String> {
+    let explicit = config_path.is_some();
+    let config_path = config_path.unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_string());
     let config_path = Path::new(&config_path);
 
     if !config_path.exists() {
-        return Err("Config not found".to_string());
+        if explicit {
+            return Err(format!(
+                "Config file {} does not exist",
+                config_path.display()
+            ));
+        }
+
+        return Ok(None);
     }
 
-    let file = File::open(config_path.to_str().unwrap());
+    let file = File::open(config_path);
     if file.is_err() {
-        return Err(format!(
-            "Could not open file {}",
-            config_path.to_str().unwrap()
-        ));
+        return Err(format!("Could not open file {}", config_path.display()));
     }
 
     let mut file = file.unwrap();
     let mut contents = String::new();
 
-    if let Err(_) = file.read_to_string(&mut contents) {
-        return Err(format!(
-            "Could not read file {}",
-            config_path.to_str().unwrap()
-        ));
+    if file.read_to_string(&mut contents).is_err() {
+        return Err(format!("Could not read file {}", config_path.display()));
     }
 
-    let deserialized = serde_json::from_str::<Config>(&contents);
-    if deserialized.is_err() {
-        return Err(format!(
-            "Could not deserialize file {}: {}",
-            config_path.to_str().unwrap(),
-            deserialized.unwrap_err()
-        ));
-    }
-
-    Ok(deserialized.unwrap())
+    serde_json::from_str::<Config>(&contents)
+        .map(Some)
+        .map_err(|err| {
+            format!(
+                "Could not deserialize file {}: {}",
+                config_path.display(),
+                err
+            )
+        })
 }
 
-pub fn get_config(config_path: Option<String>, translations_dir: Option<String>) -> Config {
-    CONFIG
-        .get_or_init(|| {
-            load_config(config_path).unwrap_or_else(|_| {
-                let mut config: Config = Default::default();
-                if let Some(translations_dir) = translations_dir {
-                    config.translations_directory = translations_dir;
-                }
-                config
-            })
-        })
-        .to_owned()
+pub fn get_config(
+    config_path: Option<String>,
+    translations_dir: Option<String>,
+) -> Result<Config, String> {
+    let config = match load_config(config_path)? {
+        Some(config) => config,
+        None => {
+            let mut config: Config = Default::default();
+            if let Some(translations_dir) = translations_dir {
+                config.translations_directory = translations_dir;
+            }
+            config
+        }
+    };
+
+    Ok(CONFIG.get_or_init(|| config).to_owned())
 }
 
 pub fn run_after_write_commands() {
@@ -88,9 +170,12 @@ pub fn run_after_write_commands() {
     }
 
     let config = config.unwrap();
-    let cwd = current_dir().unwrap();
+    let Ok(cwd) = current_dir() else {
+        eprintln!("Could not run post write commands: the working directory is not available");
+        return;
+    };
 
-    for command in config.post_write_commands.to_owned() {
+    for command in config.post_write_commands.clone() {
         println!("Executing: \"{}\"\n", command);
 
         let output = if cfg!(target_os = "windows") {
@@ -98,17 +183,23 @@ pub fn run_after_write_commands() {
                 .args(["/C", command.as_str()])
                 .current_dir(cwd.as_path())
                 .output()
-                .expect("failed to execute process")
         } else {
             Command::new("sh")
                 .arg("-c")
-                .arg(command)
+                .arg(&command)
                 .current_dir(cwd.as_path())
                 .output()
-                .expect("failed to execute process")
         };
 
-        if output.stderr.len() > 0 {
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => {
+                eprintln!("Could not run \"{}\": {}", command, err);
+                continue;
+            }
+        };
+
+        if !output.stderr.is_empty() {
             eprintln!("{}", String::from_utf8_lossy(&output.stderr));
         } else {
             println!("{}", String::from_utf8_lossy(&output.stdout));
@@ -0,0 +1,145 @@
+use crate::document::{Document, Span};
+use serde::Serialize;
+use std::env::current_dir;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub length: usize,
+    pub source_line: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub location: Option<Location>,
+    pub notes: Vec<(String, Location)>,
+}
+
+impl Location {
+    pub fn from_offset(file: &Path, source: &str, offset: usize) -> Location {
+        Location::from_span(
+            file,
+            source,
+            Span {
+                start: offset,
+                end: offset,
+            },
+        )
+    }
+
+    pub fn from_span(file: &Path, source: &str, span: Span) -> Location {
+        let start = span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = source[start..]
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or(source.len());
+        let end = span.end.clamp(start, line_end);
+
+        Location {
+            file: file.to_path_buf(),
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            length: source[start..end].chars().count().max(1),
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    pub fn in_document(file: &Path, document: &Document, span: Span) -> Location {
+        Location::from_span(file, &document.source, span)
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            display_path(&self.file),
+            self.line,
+            self.column
+        )
+    }
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: String, location: Option<Location>) -> Self {
+        Self {
+            severity,
+            message,
+            location,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn with_note(mut self, message: &str, location: Location) -> Self {
+        self.notes.push((message.to_string(), location));
+        self
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+
+        write!(f, "{}: {}", label, self.message)?;
+
+        if let Some(location) = &self.location {
+            write!(f, "\n{}", render_snippet(location))?;
+        }
+
+        for (message, location) in &self.notes {
+            write!(f, "\nnote: {}\n{}", message, render_snippet(location))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn render_snippet(location: &Location) -> String {
+    let gutter = " ".repeat(location.line.to_string().len());
+    let padding = location
+        .source_line
+        .chars()
+        .take(location.column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+
+    format!(
+        "{gutter}--> {}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+        location,
+        location.line,
+        location.source_line,
+        padding,
+        "^".repeat(location.length),
+    )
+}
+
+/// Shows paths relative to the working directory where possible, so they can be
+/// opened directly from the terminal or an editor.
+pub fn display_path(path: &Path) -> String {
+    current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+        .display()
+        .to_string()
+}
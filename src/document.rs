@@ -0,0 +1,933 @@
+use serde_json::{Map, Value};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Syntax {
+    Json,
+    // JSON5 is a superset of JSONC, so both are parsed the same way.
+    Json5,
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    String { value: String, span: Span },
+    Object { members: Vec<Member>, span: Span },
+    Array { items: Vec<Node>, span: Span },
+    Literal { value: Value, span: Span },
+}
+
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub key: String,
+    pub key_span: Span,
+    pub value: Node,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxError {
+    pub message: String,
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateKey {
+    pub key: String,
+    pub first: Span,
+    pub duplicate: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub source: String,
+    pub syntax: Syntax,
+    pub root: Node,
+}
+
+impl Syntax {
+    pub const EXTENSIONS: [&'static str; 3] = ["json", "jsonc", "json5"];
+
+    pub fn from_path(path: &Path) -> Syntax {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("jsonc") | Some("json5") => Syntax::Json5,
+            _ => Syntax::Json,
+        }
+    }
+}
+
+impl Node {
+    pub fn span(&self) -> Span {
+        match self {
+            Node::String { span, .. }
+            | Node::Object { span, .. }
+            | Node::Array { span, .. }
+            | Node::Literal { span, .. } => *span,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self {
+            Node::String { value, .. } => Value::String(value.clone()),
+            Node::Object { members, .. } => {
+                // Later members win, matching serde_json's behaviour for duplicate keys.
+                let mut map = Map::new();
+                for member in members {
+                    map.insert(member.key.clone(), member.value.to_value());
+                }
+                Value::Object(map)
+            }
+            Node::Array { items, .. } => Value::Array(items.iter().map(Node::to_value).collect()),
+            Node::Literal { value, .. } => value.clone(),
+        }
+    }
+}
+
+impl Document {
+    pub fn parse(source: String, syntax: Syntax) -> Result<Document, SyntaxError> {
+        let root = {
+            let mut parser = DocumentParser::new(&source, syntax);
+            parser.skip_bom();
+            parser.skip_trivia()?;
+            let root = parser.parse_node()?;
+            parser.skip_trivia()?;
+
+            if parser.peek().is_some() {
+                return Err(parser.error("Unexpected trailing characters"));
+            }
+
+            root
+        };
+
+        Ok(Document {
+            source,
+            syntax,
+            root,
+        })
+    }
+
+    pub fn to_value(&self) -> Value {
+        self.root.to_value()
+    }
+
+    pub fn duplicate_keys(&self) -> Vec<DuplicateKey> {
+        let mut duplicates = Vec::new();
+        collect_duplicate_keys(&self.root, "", &mut duplicates);
+        duplicates
+    }
+
+    pub fn find(&self, key: &str) -> Option<&Member> {
+        let mut current = &self.root;
+        let mut found = None;
+
+        for part in key.split('.') {
+            let Node::Object { members, .. } = current else {
+                return None;
+            };
+
+            // The last occurrence is the one that ends up in the parsed value.
+            let member = members.iter().rev().find(|m| m.key == part)?;
+            current = &member.value;
+            found = Some(member);
+        }
+
+        found
+    }
+
+    /// The text of the `//` comments on the lines directly above `key`, nearest first.
+    pub fn comments_above(&self, key: &str) -> Vec<&str> {
+        let Some(member) = self.find(key) else {
+            return Vec::new();
+        };
+
+        let source = &self.source;
+        let Some(mut line_start) = source[..member.key_span.start].rfind('\n').map(|i| i + 1)
+        else {
+            return Vec::new();
+        };
+        if !source[line_start..member.key_span.start].trim().is_empty() {
+            return Vec::new();
+        }
+
+        let mut comments = Vec::new();
+        while line_start > 0 {
+            let previous = &source[..line_start - 1];
+            line_start = previous.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+            match previous[line_start..].trim().strip_prefix("//") {
+                Some(comment) => comments.push(comment.trim()),
+                None => break,
+            }
+        }
+
+        comments
+    }
+
+    /// The value of a `// name: value` marker comment on the lines directly above `key`, or an
+    /// empty string for a `// name` marker without a value. Markers can only be placed in
+    /// .jsonc and .json5 files, as plain JSON has no comments.
+    pub fn marker(&self, key: &str, name: &str) -> Option<&str> {
+        self.comments_above(key).into_iter().find_map(|comment| {
+            let rest = comment.strip_prefix(name)?.trim_start();
+            if rest.is_empty() {
+                return Some(rest);
+            }
+
+            Some(rest.strip_prefix(':')?.trim())
+        })
+    }
+
+    /// Sets `key` to a string value by editing the source text in place, so that
+    /// comments and formatting around it are left untouched.
+    pub fn set_string(&self, key: &str, value: &str) -> Result<Document, String> {
+        let parts = key.split('.').collect::<Vec<&str>>();
+        let mut current = &self.root;
+        let mut depth = 0;
+
+        for (index, part) in parts.iter().enumerate() {
+            let Node::Object { members, .. } = current else {
+                return Err(format!("'{}' is not an object", parts[..index].join(".")));
+            };
+
+            let Some(member) = members.iter().rev().find(|m| m.key == *part) else {
+                break;
+            };
+
+            current = &member.value;
+            depth = index + 1;
+        }
+
+        let encoded = serde_json::to_string(value).map_err(|err| err.to_string())?;
+
+        if depth == parts.len() {
+            let span = current.span();
+            return self.with_edits(vec![(span, encoded)]);
+        }
+
+        let Node::Object { members, span } = current else {
+            return Err(format!("'{}' is not an object", parts[..depth].join(".")));
+        };
+
+        let object_indent = self.line_indent(span.start);
+        let indent = match members.last() {
+            Some(member) => self.line_indent(member.key_span.start),
+            None => format!("{}{}", object_indent, self.indent_unit()),
+        };
+
+        let member = self.format_member(&parts[depth..], &encoded, &indent);
+        let close = span.end - 1;
+        let inner = &self.source[span.start + 1..close];
+
+        let Some(last) = members.last() else {
+            if inner.trim().is_empty() {
+                let edit = (
+                    Span {
+                        start: span.start + 1,
+                        end: close,
+                    },
+                    format!("\n{}{}\n{}", indent, member, object_indent),
+                );
+                return self.with_edits(vec![edit]);
+            }
+
+            let insert_at = span.start + 1 + inner.trim_end().len();
+            let edit = (
+                Span {
+                    start: insert_at,
+                    end: insert_at,
+                },
+                format!("\n{}{}", indent, member),
+            );
+            return self.with_edits(vec![edit]);
+        };
+
+        let insert_at = span.start + 1 + inner.trim_end().len();
+        let mut edits = vec![(
+            Span {
+                start: insert_at,
+                end: insert_at,
+            },
+            format!("\n{}{}", indent, member),
+        )];
+
+        let last_end = last.value.span().end;
+        if !self.has_trailing_comma(last_end) {
+            edits.push((
+                Span {
+                    start: last_end,
+                    end: last_end,
+                },
+                ",".to_string(),
+            ));
+        } else if self.syntax == Syntax::Json5 {
+            // Keep the trailing comma style of the file for the new member.
+            edits[0].1.push(',');
+        }
+
+        self.with_edits(edits)
+    }
+
+    fn format_member(&self, parts: &[&str], encoded: &str, indent: &str) -> String {
+        let key = serde_json::to_string(parts[0]).unwrap();
+
+        if parts.len() == 1 {
+            return format!("{}: {}", key, encoded);
+        }
+
+        let inner_indent = format!("{}{}", indent, self.indent_unit());
+        format!(
+            "{}: {{\n{}{}\n{}}}",
+            key,
+            inner_indent,
+            self.format_member(&parts[1..], encoded, &inner_indent),
+            indent
+        )
+    }
+
+    fn with_edits(&self, mut edits: Vec<(Span, String)>) -> Result<Document, String> {
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.0.start));
+
+        let mut source = self.source.clone();
+        for (span, text) in edits {
+            source.replace_range(span.start..span.end, &text);
+        }
+
+        Document::parse(source, self.syntax).map_err(|err| err.message)
+    }
+
+    fn line_indent(&self, offset: usize) -> String {
+        let line_start = self.source[..offset]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.source[line_start..offset]
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect()
+    }
+
+    fn indent_unit(&self) -> String {
+        if let Node::Object { members, .. } = &self.root
+            && let Some(member) = members.first()
+        {
+            let indent = self.line_indent(member.key_span.start);
+            if !indent.is_empty() {
+                return indent;
+            }
+        }
+
+        "  ".to_string()
+    }
+
+    fn has_trailing_comma(&self, offset: usize) -> bool {
+        let mut parser = DocumentParser::new(&self.source, self.syntax);
+        parser.offset = offset;
+
+        parser.skip_trivia().is_ok() && parser.peek() == Some(',')
+    }
+}
+
+fn collect_duplicate_keys(node: &Node, base: &str, duplicates: &mut Vec<DuplicateKey>) {
+    let Node::Object { members, .. } = node else {
+        return;
+    };
+
+    for (index, member) in members.iter().enumerate() {
+        let key = if base.is_empty() {
+            member.key.clone()
+        } else {
+            format!("{}.{}", base, member.key)
+        };
+
+        if let Some(first) = members[..index].iter().find(|m| m.key == member.key) {
+            duplicates.push(DuplicateKey {
+                key: key.clone(),
+                first: first.key_span,
+                duplicate: member.key_span,
+            });
+        }
+
+        collect_duplicate_keys(&member.value, &key, duplicates);
+    }
+}
+
+struct DocumentParser<'a> {
+    source: &'a str,
+    syntax: Syntax,
+    offset: usize,
+}
+
+impl<'a> DocumentParser<'a> {
+    fn new(source: &'a str, syntax: Syntax) -> Self {
+        Self {
+            source,
+            syntax,
+            offset: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> SyntaxError {
+        SyntaxError {
+            message: message.to_string(),
+            offset: self.offset,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SyntaxError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => Err(self.error(&format!("Expected '{}' but found '{}'", expected, c))),
+            None => Err(self.error(&format!("Expected '{}' but reached end of file", expected))),
+        }
+    }
+
+    fn skip_bom(&mut self) {
+        if self.peek() == Some('\u{feff}') {
+            self.bump();
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), SyntaxError> {
+        let relaxed = self.syntax == Syntax::Json5;
+
+        loop {
+            let rest = &self.source[self.offset..];
+
+            match self.peek() {
+                Some(' ' | '\t' | '\n' | '\r') => {
+                    self.bump();
+                }
+                Some('\u{b}' | '\u{c}' | '\u{a0}' | '\u{2028}' | '\u{2029}' | '\u{feff}')
+                    if relaxed =>
+                {
+                    self.bump();
+                }
+                Some('/') if relaxed && rest.starts_with("//") => {
+                    let end = rest.find('\n').unwrap_or(rest.len());
+                    self.offset += end;
+                }
+                Some('/') if relaxed && rest.starts_with("/*") => {
+                    let Some(end) = rest[2..].find("*/") else {
+                        return Err(self.error("Unterminated block comment"));
+                    };
+                    self.offset += end + 4;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn parse_node(&mut self) -> Result<Node, SyntaxError> {
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => {
+                let (value, span) = self.parse_string()?;
+                Ok(Node::String { value, span })
+            }
+            Some('\'') if self.syntax == Syntax::Json5 => {
+                let (value, span) = self.parse_string()?;
+                Ok(Node::String { value, span })
+            }
+            Some(_) => self.parse_literal(),
+            None => Err(self.error("Unexpected end of file")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Node, SyntaxError> {
+        let start = self.offset;
+        self.expect('{')?;
+        self.skip_trivia()?;
+
+        let mut members = Vec::new();
+
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Node::Object {
+                members,
+                span: Span {
+                    start,
+                    end: self.offset,
+                },
+            });
+        }
+
+        loop {
+            let (key, key_span) = self.parse_key()?;
+            self.skip_trivia()?;
+            self.expect(':')?;
+            self.skip_trivia()?;
+            let value = self.parse_node()?;
+            self.skip_trivia()?;
+
+            members.push(Member {
+                key,
+                key_span,
+                value,
+            });
+
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                    self.skip_trivia()?;
+
+                    if self.syntax == Syntax::Json5 && self.peek() == Some('}') {
+                        self.bump();
+                        break;
+                    }
+                }
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                _ => return Err(self.error("Expected ',' or '}' after object member")),
+            }
+        }
+
+        Ok(Node::Object {
+            members,
+            span: Span {
+                start,
+                end: self.offset,
+            },
+        })
+    }
+
+    fn parse_array(&mut self) -> Result<Node, SyntaxError> {
+        let start = self.offset;
+        self.expect('[')?;
+        self.skip_trivia()?;
+
+        let mut items = Vec::new();
+
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(Node::Array {
+                items,
+                span: Span {
+                    start,
+                    end: self.offset,
+                },
+            });
+        }
+
+        loop {
+            items.push(self.parse_node()?);
+            self.skip_trivia()?;
+
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                    self.skip_trivia()?;
+
+                    if self.syntax == Syntax::Json5 && self.peek() == Some(']') {
+                        self.bump();
+                        break;
+                    }
+                }
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                _ => return Err(self.error("Expected ',' or ']' after array item")),
+            }
+        }
+
+        Ok(Node::Array {
+            items,
+            span: Span {
+                start,
+                end: self.offset,
+            },
+        })
+    }
+
+    fn parse_key(&mut self) -> Result<(String, Span), SyntaxError> {
+        match self.peek() {
+            Some('"') => self.parse_string(),
+            Some('\'') if self.syntax == Syntax::Json5 => self.parse_string(),
+            Some(c) if self.syntax == Syntax::Json5 && is_identifier_start(c) => {
+                let start = self.offset;
+                while self.peek().is_some_and(is_identifier_part) {
+                    self.bump();
+                }
+
+                Ok((
+                    self.source[start..self.offset].to_string(),
+                    Span {
+                        start,
+                        end: self.offset,
+                    },
+                ))
+            }
+            _ => Err(self.error("Expected a string key")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<(String, Span), SyntaxError> {
+        let start = self.offset;
+        let Some(quote) = self.bump() else {
+            return Err(self.error("Expected a string"));
+        };
+
+        let mut value = String::new();
+
+        loop {
+            match self.bump() {
+                Some(c) if c == quote => break,
+                Some('\\') if self.syntax == Syntax::Json5 => {
+                    if let Some(c) = self.parse_json5_escape()? {
+                        value.push(c);
+                    }
+                }
+                Some('\\') => value.push(self.parse_escape()?),
+                Some(c) if (c as u32) < 0x20 => {
+                    return Err(self.error("Control characters must be escaped in strings"));
+                }
+                Some(c) => value.push(c),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+
+        Ok((
+            value,
+            Span {
+                start,
+                end: self.offset,
+            },
+        ))
+    }
+
+    fn parse_escape(&mut self) -> Result<char, SyntaxError> {
+        let c = match self.bump() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let high = self.parse_hex_escape()?;
+
+                if (0xD800..0xDC00).contains(&high) {
+                    if self.source[self.offset..].starts_with("\\u") {
+                        self.offset += 2;
+                        let low = self.parse_hex_escape()?;
+
+                        if (0xDC00..0xE000).contains(&low) {
+                            let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                            return char::from_u32(code)
+                                .ok_or_else(|| self.error("Invalid unicode escape"));
+                        }
+                    }
+
+                    return Err(self.error("Lone surrogate in unicode escape"));
+                }
+
+                char::from_u32(high)
+                    .ok_or_else(|| self.error("Lone surrogate in unicode escape"))?
+            }
+            _ => return Err(self.error("Invalid escape sequence")),
+        };
+
+        Ok(c)
+    }
+
+    fn parse_json5_escape(&mut self) -> Result<Option<char>, SyntaxError> {
+        let c = match self.peek() {
+            // Line continuations are dropped from the value.
+            Some('\n' | '\u{2028}' | '\u{2029}') => {
+                self.bump();
+                return Ok(None);
+            }
+            Some('\r') => {
+                self.bump();
+                if self.peek() == Some('\n') {
+                    self.bump();
+                }
+                return Ok(None);
+            }
+            Some('\'') => '\'',
+            Some('v') => '\u{b}',
+            Some('0') => '\0',
+            Some('x') => {
+                self.bump();
+                let digits = self
+                    .source
+                    .get(self.offset..self.offset + 2)
+                    .ok_or_else(|| self.error("Incomplete hex escape"))?;
+                if !is_hex(digits) {
+                    return Err(self.error("Invalid hex escape"));
+                }
+                let code = u32::from_str_radix(digits, 16)
+                    .map_err(|_| self.error("Invalid hex escape"))?;
+                self.offset += 2;
+                return Ok(char::from_u32(code));
+            }
+            Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't' | 'u') => {
+                return self.parse_escape().map(Some);
+            }
+            Some(c) if !c.is_ascii_digit() => c,
+            _ => return Err(self.error("Invalid escape sequence")),
+        };
+
+        self.bump();
+        Ok(Some(c))
+    }
+
+    fn parse_hex_escape(&mut self) -> Result<u32, SyntaxError> {
+        let digits = self
+            .source
+            .get(self.offset..self.offset + 4)
+            .ok_or_else(|| self.error("Incomplete unicode escape"))?;
+        if !is_hex(digits) {
+            return Err(self.error("Invalid unicode escape"));
+        }
+        let code =
+            u32::from_str_radix(digits, 16).map_err(|_| self.error("Invalid unicode escape"))?;
+        self.offset += 4;
+
+        Ok(code)
+    }
+
+    fn parse_literal(&mut self) -> Result<Node, SyntaxError> {
+        let start = self.offset;
+
+        while let Some(c) = self.peek() {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        let raw = &self.source[start..self.offset];
+        if raw.is_empty() {
+            return Err(self.error(&format!(
+                "Unexpected character '{}'",
+                self.peek().unwrap_or_default()
+            )));
+        }
+
+        let value = serde_json::from_str::<Value>(raw)
+            .ok()
+            .or_else(|| match self.syntax {
+                Syntax::Json5 => parse_json5_number(raw),
+                Syntax::Json => None,
+            })
+            .ok_or_else(|| SyntaxError {
+                message: format!("Invalid value '{}'", raw),
+                offset: start,
+            })?;
+
+        Ok(Node::Literal {
+            value,
+            span: Span {
+                start,
+                end: self.offset,
+            },
+        })
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+// `from_str_radix` also accepts a leading sign, which escapes and hex numbers do not allow.
+fn is_hex(digits: &str) -> bool {
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn parse_json5_number(raw: &str) -> Option<Value> {
+    let (negative, unsigned) = match raw.as_bytes().first() {
+        Some(b'-') => (true, &raw[1..]),
+        Some(b'+') => (false, &raw[1..]),
+        _ => (false, raw),
+    };
+
+    if let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+        && is_hex(hex)
+    {
+        let number = i64::from_str_radix(hex, 16).ok()?;
+        return Some(Value::from(if negative { -number } else { number }));
+    }
+
+    // NaN and Infinity have no JSON representation.
+    if unsigned == "Infinity" || unsigned == "NaN" {
+        return Some(Value::Null);
+    }
+
+    let number = unsigned.parse::<f64>().ok()?;
+    serde_json::Number::from_f64(if negative { -number } else { number }).map(Value::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str, syntax: Syntax) -> Document {
+        Document::parse(source.to_string(), syntax).unwrap()
+    }
+
+    fn string(document: &Document, key: &str) -> String {
+        match &document.find(key).unwrap().value {
+            Node::String { value, .. } => value.clone(),
+            node => panic!("'{}' is not a string: {:?}", key, node),
+        }
+    }
+
+    #[test]
+    fn parses_json_escapes() {
+        let document = parse(
+            r#"{"a": "\u00e9\n\t\"\\\/", "b": "\ud83d\ude00"}"#,
+            Syntax::Json,
+        );
+
+        assert_eq!(string(&document, "a"), "é\n\t\"\\/");
+        assert_eq!(string(&document, "b"), "😀");
+    }
+
+    #[test]
+    fn parses_json5_escapes() {
+        let document = parse(
+            "{a: 'it\\'s \\x41\\v', b: \"line \\\ncontinued\", c: \"\\q\"}",
+            Syntax::Json5,
+        );
+
+        assert_eq!(string(&document, "a"), "it's A\u{b}");
+        assert_eq!(string(&document, "b"), "line continued");
+        assert_eq!(string(&document, "c"), "q");
+    }
+
+    #[test]
+    fn rejects_invalid_escapes() {
+        for source in [
+            r#"{"a": "\u+123"}"#,
+            r#"{"a": "\u12"}"#,
+            r#"{"a": "\ud83d"}"#,
+            r#"{"a": "\x41"}"#,
+        ] {
+            assert!(
+                Document::parse(source.to_string(), Syntax::Json).is_err(),
+                "{}",
+                source
+            );
+        }
+
+        for source in [r#"{a: "\x+1"}"#, r#"{a: "\u-123"}"#, r#"{a: 0x+1}"#] {
+            assert!(
+                Document::parse(source.to_string(), Syntax::Json5).is_err(),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn trailing_commas_are_json5_only() {
+        let source = r#"{"a": "A", "b": ["x", "y",],}"#;
+
+        assert!(Document::parse(source.to_string(), Syntax::Json).is_err());
+
+        let document = parse(source, Syntax::Json5);
+        assert_eq!(
+            document.to_value(),
+            serde_json::json!({"a": "A", "b": ["x", "y"]})
+        );
+    }
+
+    #[test]
+    fn reads_comments_above_keys() {
+        let document = parse(
+            r#"{
+  /* not a line comment */
+  "a": "A",
+  // far
+  // tt-max-length: 10
+  "b": "B", // after
+  // tt-ignore
+  "c": "C"
+}"#,
+            Syntax::Json5,
+        );
+
+        assert!(document.comments_above("a").is_empty());
+        assert_eq!(
+            document.comments_above("b"),
+            vec!["tt-max-length: 10", "far"]
+        );
+        assert_eq!(document.marker("b", "tt-max-length"), Some("10"));
+        assert_eq!(document.marker("c", "tt-ignore"), Some(""));
+        assert_eq!(document.marker("c", "tt-max-length"), None);
+        assert_eq!(string(&document, "b"), "B");
+    }
+
+    #[test]
+    fn set_string_replaces_in_place() {
+        let document = parse(
+            "{\n  // greeting\n  \"a\": \"Hello\", // trailing\n  \"b\": {\"c\": 'C',},\n}\n",
+            Syntax::Json5,
+        );
+
+        let document = document.set_string("a", "Hi \"you\"").unwrap();
+        let document = document.set_string("b.c", "D").unwrap();
+
+        assert_eq!(
+            document.source,
+            "{\n  // greeting\n  \"a\": \"Hi \\\"you\\\"\", // trailing\n  \"b\": {\"c\": \"D\",},\n}\n"
+        );
+    }
+
+    #[test]
+    fn set_string_inserts_keys() {
+        let document = parse("{\n  \"a\": \"A\"\n}\n", Syntax::Json);
+        let document = document.set_string("b", "B").unwrap();
+        assert_eq!(document.source, "{\n  \"a\": \"A\",\n  \"b\": \"B\"\n}\n");
+
+        let document = parse("{\n  // a\n  \"a\": \"A\",\n}\n", Syntax::Json5);
+        let document = document.set_string("n.b", "B").unwrap();
+        assert_eq!(
+            document.to_value(),
+            serde_json::json!({"a": "A", "n": {"b": "B"}})
+        );
+        assert!(document.source.starts_with("{\n  // a\n  \"a\": \"A\",\n"));
+        assert!(document.source.trim_end().ends_with(",\n}"));
+
+        let document = parse("{}", Syntax::Json);
+        let document = document.set_string("a", "A").unwrap();
+        assert_eq!(document.to_value(), serde_json::json!({"a": "A"}));
+    }
+
+    #[test]
+    fn set_string_rejects_non_objects() {
+        let document = parse(r#"{"a": "A"}"#, Syntax::Json);
+
+        assert!(document.set_string("a.b", "B").is_err());
+    }
+}
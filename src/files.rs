@@ -1,9 +1,12 @@
 use crate::config::CONFIG;
-use crate::files;
+use crate::document::{Document, Syntax};
+use crate::parser::ParserError;
+use crate::{files, parser};
 use serde_json::Value;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::PathBuf;
+use unicode_normalization::UnicodeNormalization;
 
 pub fn get_files_in_directory(
     target_dir: PathBuf,
@@ -25,21 +28,31 @@ pub fn get_files_in_directory(
     Ok(res)
 }
 
-pub fn get_translation_files(target_path: PathBuf) -> Result<(PathBuf, Vec<PathBuf>), String> {
+pub fn get_translation_files(target_path: PathBuf) -> Result<(PathBuf, Vec<PathBuf>), ParserError> {
     let config = CONFIG.get().unwrap();
 
-    let translation_files = files::get_files_in_directory(target_path.clone(), "json".to_string())?;
+    let mut translation_files = Vec::new();
+    for extension in Syntax::EXTENSIONS {
+        translation_files.extend(
+            files::get_files_in_directory(target_path.clone(), extension.to_string()).map_err(
+                |error| ParserError::CouldNotReadDirectory {
+                    path: target_path.clone(),
+                    error,
+                },
+            )?,
+        );
+    }
+
+    translation_files.sort();
 
-    let default_locale_file_name = format!("{}.json", config.default_locale);
     let default_locale_path = &translation_files
         .iter()
-        .find(|p| p.file_name() == Some(OsStr::new(&default_locale_file_name)));
+        .find(|p| p.file_stem() == Some(OsStr::new(&config.default_locale)));
 
     if default_locale_path.is_none() {
-        return Err(format!(
-            "Could not find default translation file. Searching for {}",
-            default_locale_file_name
-        ));
+        return Err(ParserError::DefaultLocaleNotFound {
+            locale: config.default_locale.clone(),
+        });
     }
 
     let default_locale_path = default_locale_path.unwrap();
@@ -47,6 +60,10 @@ pub fn get_translation_files(target_path: PathBuf) -> Result<(PathBuf, Vec<PathB
     Ok((default_locale_path.to_owned(), translation_files))
 }
 
+fn normalize_on_write() -> bool {
+    CONFIG.get().is_some_and(|config| config.normalize_on_write)
+}
+
 pub fn write_translation_file(file_path: PathBuf, content: Value) -> Result<(), String> {
     fs::write(
         file_path,
@@ -56,3 +73,48 @@ pub fn write_translation_file(file_path: PathBuf, content: Value) -> Result<(),
 
     Ok(())
 }
+
+pub fn write_translation_key(
+    file_path: PathBuf,
+    document: Document,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    write_translation_keys(file_path, document, vec![(key, value)])
+}
+
+/// Sets several keys at once and writes the file a single time.
+pub fn write_translation_keys(
+    file_path: PathBuf,
+    document: Document,
+    values: Vec<(String, String)>,
+) -> Result<(), String> {
+    // Only the written values are normalized, in both syntaxes, so keys and the other values
+    // are left as they are.
+    let values = values.into_iter().map(|(key, value)| {
+        if normalize_on_write() {
+            (key, value.nfc().collect::<String>())
+        } else {
+            (key, value)
+        }
+    });
+
+    match document.syntax {
+        Syntax::Json => {
+            let mut content = document.to_value();
+            for (key, value) in values {
+                content = parser::update_translation_key(content, key, value)
+                    .map_err(|err| err.to_string())?;
+            }
+            write_translation_file(file_path, content)
+        }
+        // Edit the source in place so comments and formatting are preserved.
+        Syntax::Json5 => {
+            let mut document = document;
+            for (key, value) in values {
+                document = document.set_string(&key, &value)?;
+            }
+            fs::write(file_path, document.source).map_err(|err| err.to_string())
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Reads `file` as it was at `reference`, or `None` if it did not exist at that point.
+pub fn read_file_at(file: &Path, reference: &str) -> Result<Option<String>, String> {
+    let directory = file
+        .parent()
+        .ok_or_else(|| format!("{} is not in a directory", file.display()))?;
+    let root = git(directory, &["rev-parse", "--show-toplevel"])
+        .map_err(|err| format!("{} is not in a git repository: {}", file.display(), err))?;
+
+    git(
+        directory,
+        &[
+            "rev-parse",
+            "--verify",
+            &format!("{}^{{commit}}", reference),
+        ],
+    )
+    .map_err(|_| format!("Unknown git ref '{}'", reference))?;
+
+    let file = file
+        .canonicalize()
+        .map_err(|err| format!("Could not resolve {}: {}", file.display(), err))?;
+    let root = Path::new(root.trim())
+        .canonicalize()
+        .map_err(|err| format!("Could not resolve git repository {}: {}", root.trim(), err))?;
+    let relative = file
+        .strip_prefix(&root)
+        .ok()
+        .and_then(|relative| relative.to_str())
+        .ok_or_else(|| {
+            format!(
+                "{} is not a UTF-8 path in git repository {}",
+                file.display(),
+                root.display()
+            )
+        })?;
+
+    // Git expects forward slashes in revision paths, also on Windows.
+    let object = format!("{}:{}", reference, relative.replace('\\', "/"));
+    if git(&root, &["cat-file", "-e", &object]).is_err() {
+        return Ok(None);
+    }
+
+    git(&root, &["show", &object]).map(Some)
+}
+
+fn git(directory: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|err| format!("Could not run git: {}", err))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
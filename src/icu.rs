@@ -0,0 +1,321 @@
+use crate::document::SyntaxError;
+
+/// An argument of an ICU message, such as `{name}` or `{count, plural, ...}`.
+#[derive(Debug, Clone)]
+pub struct Argument {
+    pub name: String,
+    /// The argument type, such as `number` or `plural`, or empty for a simple argument.
+    pub kind: String,
+    /// Byte offset of the opening brace in the message.
+    pub offset: usize,
+    /// The selectors of the branches of a `plural`, `selectordinal` or `select` argument.
+    pub branches: Vec<Branch>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Branch {
+    pub selector: String,
+    pub offset: usize,
+}
+
+impl Argument {
+    pub fn has_branches(&self) -> bool {
+        matches!(self.kind.as_str(), "plural" | "selectordinal" | "select")
+    }
+
+    pub fn kind_name(&self) -> &str {
+        if self.kind.is_empty() {
+            "simple"
+        } else {
+            &self.kind
+        }
+    }
+}
+
+const SIMPLE_TYPES: [&str; 6] = ["number", "date", "time", "spellout", "ordinal", "duration"];
+
+/// Parses `message` as an ICU MessageFormat pattern, returning every argument in it,
+/// including those nested in branches.
+pub fn parse(message: &str) -> Result<Vec<Argument>, SyntaxError> {
+    let mut parser = MessageParser {
+        source: message,
+        position: 0,
+        arguments: Vec::new(),
+    };
+
+    parser.parse_message(false, false)?;
+    Ok(parser.arguments)
+}
+
+struct MessageParser<'a> {
+    source: &'a str,
+    position: usize,
+    arguments: Vec<Argument>,
+}
+
+impl<'a> MessageParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> SyntaxError {
+        SyntaxError {
+            message: message.to_string(),
+            offset: self.position,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &str) -> Result<(), SyntaxError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(message));
+        }
+
+        self.next();
+        Ok(())
+    }
+
+    fn read_word(&mut self) -> &'a str {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !"{}'#,:|=<>".contains(c))
+        {
+            self.next();
+        }
+
+        &self.source[start..self.position]
+    }
+
+    /// Parses text and arguments, up to the end of the message or the `}` closing a branch.
+    fn parse_message(&mut self, in_plural: bool, nested: bool) -> Result<(), SyntaxError> {
+        loop {
+            match self.peek() {
+                None if nested => return Err(self.error("Expected '}' to close the branch")),
+                None => return Ok(()),
+                Some('}') if nested => return Ok(()),
+                Some('}') => {
+                    return Err(self.error("Unexpected '}', quote it as '}' to use it as text"));
+                }
+                Some('{') => self.parse_argument()?,
+                Some('\'') => self.skip_quoted(in_plural),
+                Some(_) => {
+                    self.next();
+                }
+            }
+        }
+    }
+
+    /// Skips an apostrophe, and the text it quotes if it starts quoting. Two apostrophes are
+    /// a literal apostrophe, and a single one only quotes if followed by a special character.
+    fn skip_quoted(&mut self, in_plural: bool) {
+        self.next();
+
+        match self.peek() {
+            Some('\'') => {
+                self.next();
+            }
+            Some('{' | '}' | '|') => self.skip_until_quote(),
+            Some('#') if in_plural => self.skip_until_quote(),
+            _ => {}
+        }
+    }
+
+    fn skip_until_quote(&mut self) {
+        while let Some(c) = self.next() {
+            if c == '\'' {
+                if self.peek() != Some('\'') {
+                    return;
+                }
+
+                self.next();
+            }
+        }
+    }
+
+    fn parse_argument(&mut self) -> Result<(), SyntaxError> {
+        let offset = self.position;
+        self.next();
+        self.skip_whitespace();
+
+        let name = self.read_word().to_string();
+        if name.is_empty() {
+            return Err(self.error("Expected an argument name"));
+        }
+
+        self.skip_whitespace();
+        let mut argument = Argument {
+            name,
+            kind: String::new(),
+            offset,
+            branches: Vec::new(),
+        };
+
+        match self.next() {
+            Some('}') => {}
+            Some(',') => {
+                self.skip_whitespace();
+                argument.kind = self.read_word().to_string();
+                self.skip_whitespace();
+
+                match argument.kind.as_str() {
+                    "" => return Err(self.error("Expected an argument type")),
+                    "plural" | "selectordinal" | "select" => {
+                        self.expect(',', "Expected ',' after the argument type")?;
+                        self.parse_branches(&mut argument)?;
+                    }
+                    kind if SIMPLE_TYPES.contains(&kind) => self.parse_style()?,
+                    kind => {
+                        return Err(SyntaxError {
+                            message: format!("Unknown argument type '{}'", kind),
+                            offset: self.position - kind.len(),
+                        });
+                    }
+                }
+            }
+            _ => return Err(self.error("Expected ',' or '}' after the argument name")),
+        }
+
+        self.arguments.push(argument);
+        Ok(())
+    }
+
+    /// Skips the optional style of a simple argument, such as `, currency` or `, ::percent`.
+    fn parse_style(&mut self) -> Result<(), SyntaxError> {
+        if self.peek() == Some(',') {
+            let mut depth = 0;
+            loop {
+                match self.next() {
+                    None => return Err(self.error("Expected '}' to close the argument")),
+                    Some('\'') => self.skip_until_quote(),
+                    Some('{') => depth += 1,
+                    Some('}') if depth == 0 => return Ok(()),
+                    Some('}') => depth -= 1,
+                    Some(_) => {}
+                }
+            }
+        }
+
+        self.expect('}', "Expected ',' or '}' after the argument type")
+    }
+
+    fn parse_branches(&mut self, argument: &mut Argument) -> Result<(), SyntaxError> {
+        let in_plural = argument.kind != "select";
+        self.skip_whitespace();
+
+        if in_plural && self.source[self.position..].starts_with("offset:") {
+            self.position += "offset:".len();
+            self.skip_whitespace();
+            if self.read_word().parse::<u32>().is_err() {
+                return Err(self.error("Expected a number after 'offset:'"));
+            }
+        }
+
+        loop {
+            self.skip_whitespace();
+            let offset = self.position;
+
+            let selector = match self.peek() {
+                None => return Err(self.error("Expected '}' to close the argument")),
+                Some('}') => {
+                    self.next();
+                    break;
+                }
+                Some('=') => {
+                    self.next();
+                    let value = self.read_word();
+                    if value.parse::<f64>().is_err() {
+                        return Err(self.error("Expected a number after '='"));
+                    }
+                    format!("={}", value)
+                }
+                Some(_) => self.read_word().to_string(),
+            };
+
+            if selector.is_empty() {
+                return Err(self.error("Expected a selector"));
+            }
+
+            self.skip_whitespace();
+            self.expect('{', &format!("Expected '{{' after selector '{}'", selector))?;
+            self.parse_message(in_plural, true)?;
+            self.next();
+
+            argument.branches.push(Branch { selector, offset });
+        }
+
+        if argument.branches.is_empty() {
+            return Err(SyntaxError {
+                message: format!("'{}' argument has no branches", argument.kind),
+                offset: argument.offset,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(message: &str) -> Vec<(String, String, usize)> {
+        parse(message)
+            .unwrap()
+            .into_iter()
+            .map(|argument| {
+                let kind = argument.kind_name().to_string();
+                (argument.name, kind, argument.offset)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_nested_arguments() {
+        assert_eq!(
+            summary("Hi {name}, {count, plural, =0 {none} other {# by {author}}}"),
+            [
+                ("name".to_string(), "simple".to_string(), 3),
+                ("author".to_string(), "simple".to_string(), 49),
+                ("count".to_string(), "plural".to_string(), 11),
+            ]
+        );
+
+        let arguments = parse("{count, plural, offset:1 =0 {none} one {#} other {#}}").unwrap();
+        let selectors = arguments[0]
+            .branches
+            .iter()
+            .map(|branch| branch.selector.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(selectors, ["=0", "one", "other"]);
+    }
+
+    #[test]
+    fn skips_quoted_text() {
+        assert!(parse("'{name}' and '' quotes").unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_offsets() {
+        let err = parse("Hi {name").unwrap_err();
+        assert_eq!(err.offset, 8);
+
+        let err = parse("{count, plural, one}").unwrap_err();
+        assert!(err.message.contains("'{'"), "{}", err.message);
+
+        let err = parse("{count, plural,}").unwrap_err();
+        assert_eq!(err.message, "'plural' argument has no branches");
+        assert_eq!(err.offset, 0);
+    }
+}
@@ -1,71 +1,90 @@
+pub mod backup;
+pub mod baseline;
+pub mod checks;
 pub mod cli;
 mod commands;
 pub mod config;
+pub mod diagnostics;
+pub mod document;
 pub mod files;
+pub mod git;
+pub mod icu;
 pub mod parser;
+pub mod plurals;
+pub mod reports;
+pub mod suppression;
+pub mod validation;
 
-use crate::cli::{Cli, CommandArgs};
+use crate::cli::{Cli, CommandArgs, OutputFormat};
+use crate::commands::CommandError;
 use clap::Parser;
 use std::path::Path;
 use std::process;
 
 fn main() {
     let args = Cli::parse();
+    let output = args.output;
 
+    if let Err(err) = run(args) {
+        // A failed validation has already printed its document.
+        if output == OutputFormat::Json && !matches!(err, CommandError::ValidationFailed(_)) {
+            println!("{}", reports::json::error_report(&err));
+        }
+
+        eprintln!("{}", err);
+        process::exit(err.exit_code());
+    }
+}
+
+fn run(args: Cli) -> Result<(), CommandError> {
     let config = config::get_config(
-        args.config
-            .clone()
-            .and_then(|x| Some(x.to_string()))
-            .or_else(|| Some("tt.config.json".to_string())),
-        args.translations_dir
-            .clone()
-            .and_then(|x| Some(x.to_string())),
-    );
+        args.config.clone().map(|x| x.to_string()),
+        args.translations_dir.clone().map(|x| x.to_string()),
+    )
+    .map_err(CommandError::Config)?;
 
     // If config flag is set, we use that as the parent directory,
     // else, we use the current working directory.
-    let parent = args
-        .config
-        .clone()
-        .and_then(|x| {
-            if !x.exists() {
-                panic!("'{}' does not exist", x)
-            }
-            if x.parent().is_none() {
-                panic!("'{}' does not have a parent", x)
-            }
+    let parent = match &args.config {
+        Some(path) => {
+            let parent = match path.parent() {
+                Some(parent) if !parent.as_str().is_empty() => parent.as_std_path(),
+                _ => Path::new("."),
+            };
 
-            Some(
-                x.as_std_path()
-                    .parent()
-                    .unwrap()
-                    .canonicalize()
-                    .unwrap()
-                    .to_owned(),
-            )
-        })
-        .unwrap_or(std::env::current_dir().unwrap());
-    
-    let translations_directory = Path::new(&parent).join(config.translations_directory);
+            parent.canonicalize().map_err(|err| {
+                CommandError::Config(format!(
+                    "Could not resolve the directory of config file {}: {}",
+                    path, err
+                ))
+            })?
+        }
+        None => std::env::current_dir().map_err(|err| {
+            CommandError::Io(format!("Could not read the working directory: {}", err))
+        })?,
+    };
+
+    let translations_directory = parent.join(config.translations_directory);
+    let backup_directory = parent.join(config.backup_directory);
+    let baseline_file = parent.join(config.baseline_file);
 
     if !translations_directory.exists() {
-        panic!(
-            "Translations directory {:#?} does not exist. You should probably specify a \
-            translations directory by using '--translations-dir <path>' (or `-t <path>` for short)",
-            translations_directory
-        );
+        return Err(CommandError::Config(format!(
+            "Translations directory {} does not exist. You should probably specify a \
+            translations directory by using '--translations-dir <path>' (or '-t <path>' for short), \
+            or set 'translations_directory' in {}",
+            translations_directory.display(),
+            config::DEFAULT_CONFIG_PATH
+        )));
     }
 
-    let result = commands::handle_command(
+    commands::handle_command(
         args.clone().command,
         CommandArgs {
             translations_directory,
+            backup_directory,
+            baseline_file,
             cli_args: args,
         },
-    );
-
-    if result.is_err() {
-        eprintln!("{}", result.unwrap_err());
-        process::exit(1);
-    }
+    )
 }
@@ -1,3 +1,5 @@
+use crate::diagnostics::{Diagnostic, Location, Severity};
+use crate::document::{Document, Syntax};
 use serde_json::{Map, Value};
 use std::fmt::Display;
 use std::fs::File;
@@ -8,7 +10,10 @@ use std::path::PathBuf;
 pub enum ParserError {
     FileDoesNotExist(PathBuf),
     CouldNotOpenFile(PathBuf),
-    CouldNotParseFile(PathBuf),
+    InvalidFileName(PathBuf),
+    CouldNotReadDirectory { path: PathBuf, error: String },
+    DefaultLocaleNotFound { locale: String },
+    CouldNotParseFile(Box<Diagnostic>),
     InvalidValueType { key: String, value_type: String },
     Generic(String),
 }
@@ -22,9 +27,20 @@ impl Display for ParserError {
             ParserError::CouldNotOpenFile(path) => {
                 format!("Could not open file: {}", path.display())
             }
-            ParserError::CouldNotParseFile(path) => {
-                format!("Could not parse file: {}", path.display())
+            ParserError::InvalidFileName(path) => {
+                format!("File name is not valid UTF-8: {}", path.display())
             }
+            ParserError::CouldNotReadDirectory { path, error } => {
+                format!("Could not read directory {}: {}", path.display(), error)
+            }
+            ParserError::DefaultLocaleNotFound { locale } => {
+                format!(
+                    "Could not find default translation file. Searching for {}.{}. Set 'default_locale' in tt.config.json if your default locale is different.",
+                    locale,
+                    Syntax::EXTENSIONS.join(", .")
+                )
+            }
+            ParserError::CouldNotParseFile(diagnostic) => diagnostic.to_string(),
             ParserError::InvalidValueType { key, value_type } => {
                 format!(
                     "Invalid value '{}' for key: '{}', it should be either a string or map with strings.",
@@ -37,12 +53,12 @@ impl Display for ParserError {
     }
 }
 
-pub fn get_parsed_translation_file(path: PathBuf) -> Result<Value, ParserError> {
+pub fn get_translation_document(path: PathBuf) -> Result<Document, ParserError> {
     if !path.exists() {
         return Err(ParserError::FileDoesNotExist(path));
     }
 
-    let file = File::open(path.to_str().unwrap());
+    let file = File::open(&path);
     if file.is_err() {
         return Err(ParserError::CouldNotOpenFile(path));
     }
@@ -50,14 +66,26 @@ pub fn get_parsed_translation_file(path: PathBuf) -> Result<Value, ParserError>
     let mut file = file.unwrap();
     let mut contents = String::new();
 
-    file.read_to_string(&mut contents).unwrap();
-
-    let parsed = serde_json::from_str(&contents);
-    if parsed.is_err() {
-        return Err(ParserError::CouldNotParseFile(path));
+    if let Err(err) = file.read_to_string(&mut contents) {
+        return Err(ParserError::CouldNotParseFile(Box::new(Diagnostic::new(
+            Severity::Error,
+            format!("Could not parse file {}: {}", path.display(), err),
+            None,
+        ))));
     }
 
-    Ok(parsed.unwrap())
+    let syntax = Syntax::from_path(&path);
+    Document::parse(contents.clone(), syntax).map_err(|err| {
+        ParserError::CouldNotParseFile(Box::new(Diagnostic::new(
+            Severity::Error,
+            format!("Could not parse file: {}", err.message),
+            Some(Location::from_offset(&path, &contents, err.offset)),
+        )))
+    })
+}
+
+pub fn get_parsed_translation_file(path: PathBuf) -> Result<Value, ParserError> {
+    Ok(get_translation_document(path)?.to_value())
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -116,7 +144,7 @@ pub fn update_translation_key(
 ) -> Result<Value, ParserError> {
     let split = key.split('.').collect::<Vec<&str>>();
 
-    if split.len() < 1 {
+    if split.is_empty() {
         return Err(ParserError::Generic("Invalid key".to_string()));
     }
 
@@ -157,7 +185,7 @@ pub fn update_translation_key(
 pub fn get_translation_value(value: Value, key: String) -> Result<String, ParserError> {
     let split = key.split('.').collect::<Vec<&str>>();
 
-    if split.len() < 1 {
+    if split.is_empty() {
         return Err(ParserError::Generic("Invalid key".to_string()));
     }
 
@@ -0,0 +1,283 @@
+use crate::validation::{LocaleFile, locale_language};
+
+pub const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+// Cardinal plural categories from the CLDR plural rules, by language.
+const RULES: [(&[&str], &[&str]); 10] = [
+    (
+        &[
+            "bo", "dz", "id", "ig", "ii", "ja", "jv", "kde", "kea", "km", "ko", "lo", "ms", "my",
+            "sah", "ses", "sg", "th", "to", "vi", "wo", "yo", "yue", "zh",
+        ],
+        &["other"],
+    ),
+    (
+        &[
+            "af", "am", "as", "ast", "az", "bg", "bn", "da", "de", "el", "en", "et", "eu", "fa",
+            "fi", "fil", "fo", "fy", "gl", "gu", "ha", "hi", "hu", "hy", "is", "ka", "kk", "kn",
+            "ky", "lb", "mk", "ml", "mn", "mr", "nb", "ne", "nl", "nn", "no", "or", "pa", "ps",
+            "sd", "si", "so", "sq", "sv", "sw", "ta", "te", "tk", "tl", "tr", "ug", "ur", "uz",
+            "xh", "zu",
+        ],
+        &["one", "other"],
+    ),
+    (&["ca", "es", "fr", "it", "pt"], &["one", "many", "other"]),
+    (
+        &["he", "iu", "naq", "se", "sma", "smi", "smj", "smn", "sms"],
+        &["one", "two", "other"],
+    ),
+    (
+        &["bs", "hr", "mo", "ro", "sh", "sr"],
+        &["one", "few", "other"],
+    ),
+    (
+        &["be", "cs", "lt", "pl", "ru", "sk", "uk"],
+        &["one", "few", "many", "other"],
+    ),
+    (&["ksh", "lag", "lv", "prg"], &["zero", "one", "other"]),
+    (&["dsb", "gd", "hsb", "sl"], &["one", "two", "few", "other"]),
+    (
+        &["br", "ga", "gv", "mt"],
+        &["one", "two", "few", "many", "other"],
+    ),
+    (
+        &["ar", "cy", "kw"],
+        &["zero", "one", "two", "few", "many", "other"],
+    ),
+];
+
+/// The plural categories `locale` needs, or `None` if its language is not known.
+pub fn categories(locale: &str) -> Option<&'static [&'static str]> {
+    let language = locale_language(locale);
+
+    RULES
+        .iter()
+        .find(|(languages, _)| languages.contains(&language.as_str()))
+        .map(|(_, categories)| *categories)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PluralStyle {
+    // i18next style `items_one` and `items_other` keys.
+    Suffix,
+    // `items.one` and `items.other` keys in an object.
+    Nested,
+}
+
+/// A key with a form for every plural category, such as `items_one` and `items_other`.
+#[derive(Debug, Clone)]
+pub struct PluralGroup {
+    pub base: String,
+    pub style: PluralStyle,
+}
+
+impl PluralGroup {
+    pub fn key(&self, category: &str) -> String {
+        match self.style {
+            PluralStyle::Suffix => format!("{}_{}", self.base, category),
+            PluralStyle::Nested => format!("{}.{}", self.base, category),
+        }
+    }
+
+    /// The plural category of `key`, if it is one of the forms of this group.
+    pub fn category(&self, key: &str) -> Option<&'static str> {
+        let separator = match self.style {
+            PluralStyle::Suffix => '_',
+            PluralStyle::Nested => '.',
+        };
+
+        let category = key.strip_prefix(&self.base)?.strip_prefix(separator)?;
+        CATEGORIES.into_iter().find(|c| *c == category)
+    }
+}
+
+/// Finds the plural groups in the default locale. A group needs an `other` form and at least
+/// one form for another plural category, so keys like `reason_other` or an object with only an
+/// `other` key are not mistaken for plurals. For nested groups every key of the object has to
+/// be a plural category.
+pub fn plural_groups(default_locale: &LocaleFile) -> Vec<PluralGroup> {
+    let mut groups = Vec::new();
+
+    for key in &default_locale.keys {
+        let candidate = if let Some(base) = key.strip_suffix("_other")
+            // Ordinal forms use different categories, which are not checked.
+            && !base.ends_with("_ordinal")
+        {
+            PluralGroup {
+                base: base.to_string(),
+                style: PluralStyle::Suffix,
+            }
+        } else if let Some(base) = key.strip_suffix(".other") {
+            PluralGroup {
+                base: base.to_string(),
+                style: PluralStyle::Nested,
+            }
+        } else {
+            continue;
+        };
+
+        let has_other_category = CATEGORIES
+            .iter()
+            .filter(|category| **category != "other")
+            .any(|category| default_locale.keys.contains(&candidate.key(category)));
+        if !has_other_category {
+            continue;
+        }
+
+        if candidate.style == PluralStyle::Nested {
+            let prefix = format!("{}.", candidate.base);
+            let only_categories = default_locale
+                .keys
+                .iter()
+                .filter_map(|key| key.strip_prefix(&prefix))
+                .all(|category| CATEGORIES.contains(&category));
+
+            if !only_categories {
+                continue;
+            }
+        }
+
+        groups.push(candidate);
+    }
+
+    groups
+}
+
+/// The group and category of `key`, if it is a plural form.
+pub fn plural_form<'a>(
+    groups: &'a [PluralGroup],
+    key: &str,
+) -> Option<(&'a PluralGroup, &'static str)> {
+    groups
+        .iter()
+        .find_map(|group| Some((group, group.category(key)?)))
+}
+
+/// The keys `locale` should have: the keys of the default locale, with the forms of plural
+/// groups replaced by the forms for the plural categories of `locale`.
+pub fn expected_keys(
+    default_locale: &LocaleFile,
+    groups: &[PluralGroup],
+    locale: &str,
+) -> Vec<String> {
+    let Some(categories) = categories(locale) else {
+        return default_locale.keys.clone();
+    };
+
+    let mut keys = Vec::new();
+    for key in &default_locale.keys {
+        let Some((group, _)) = plural_form(groups, key) else {
+            keys.push(key.clone());
+            continue;
+        };
+
+        for category in categories {
+            let key = group.key(category);
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    keys
+}
+
+/// Whether `category` is a form `locale` does not use. i18next also uses `zero` forms in
+/// languages without a zero category, so those are always accepted.
+pub fn is_superfluous(group: &PluralGroup, category: &str, locale: &str) -> bool {
+    let Some(categories) = categories(locale) else {
+        return false;
+    };
+
+    if group.style == PluralStyle::Suffix && category == "zero" {
+        return false;
+    }
+
+    !categories.contains(&category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::locale_file;
+
+    #[test]
+    fn uses_cldr_categories() {
+        assert_eq!(categories("pl"), Some(&["one", "few", "many", "other"][..]));
+        assert_eq!(
+            categories("ar-EG"),
+            Some(&["zero", "one", "two", "few", "many", "other"][..])
+        );
+        assert_eq!(categories("ja"), Some(&["other"][..]));
+        assert_eq!(categories("pt_BR"), Some(&["one", "many", "other"][..]));
+        assert_eq!(categories("tlh"), None);
+    }
+
+    #[test]
+    fn finds_plural_groups() {
+        let en = locale_file(
+            "en",
+            "{items_one: '', items_other: '', reason_other: '', rank_ordinal_one: '', \
+             rank_ordinal_other: '', days: {one: '', other: ''}, user: {name: '', other: ''}}",
+        );
+
+        let groups = plural_groups(&en);
+        let bases = groups
+            .iter()
+            .map(|group| (group.base.as_str(), group.style))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bases,
+            [
+                ("days", PluralStyle::Nested),
+                ("items", PluralStyle::Suffix)
+            ]
+        );
+
+        assert_eq!(
+            plural_form(&groups, "items_few").map(|(group, category)| (&*group.base, category)),
+            Some(("items", "few"))
+        );
+        assert!(plural_form(&groups, "items_total").is_none());
+    }
+
+    #[test]
+    fn expects_the_forms_of_the_locale() {
+        let en = locale_file("en", "{title: '', items_one: '', items_other: ''}");
+        let groups = plural_groups(&en);
+
+        assert_eq!(
+            expected_keys(&en, &groups, "pl"),
+            [
+                "items_one",
+                "items_few",
+                "items_many",
+                "items_other",
+                "title"
+            ]
+        );
+        assert_eq!(expected_keys(&en, &groups, "ja"), ["items_other", "title"]);
+        assert_eq!(
+            expected_keys(&en, &groups, "tlh"),
+            ["items_one", "items_other", "title"]
+        );
+    }
+
+    #[test]
+    fn reports_superfluous_forms() {
+        let suffix = PluralGroup {
+            base: "items".to_string(),
+            style: PluralStyle::Suffix,
+        };
+        let nested = PluralGroup {
+            base: "items".to_string(),
+            style: PluralStyle::Nested,
+        };
+
+        assert!(is_superfluous(&suffix, "one", "ja"));
+        assert!(!is_superfluous(&suffix, "zero", "de"));
+        assert!(is_superfluous(&nested, "zero", "de"));
+        assert!(!is_superfluous(&suffix, "few", "pl"));
+        assert!(!is_superfluous(&suffix, "few", "tlh"));
+    }
+}
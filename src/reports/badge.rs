@@ -0,0 +1,94 @@
+use crate::config::BadgeThreshold;
+use crate::reports::escape_xml;
+
+const FALLBACK_COLOR: &str = "#9f9f9f";
+
+/// Renders a flat badge in the style of shields.io.
+pub fn badge(label: &str, message: &str, color: &str) -> String {
+    let label_width = text_width(label) + 10;
+    let message_width = text_width(message) + 10;
+    let width = label_width + message_width;
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">
+  <title>{label}: {message}</title>
+  <linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>
+  <clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="15" fill="#010101" fill-opacity=".3">{label}</text>
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{message_x}" y="15" fill="#010101" fill-opacity=".3">{message}</text>
+    <text x="{message_x}" y="14">{message}</text>
+  </g>
+</svg>
+"##,
+        width = width,
+        label_width = label_width,
+        message_width = message_width,
+        label_x = label_width as f64 / 2.0,
+        message_x = label_width as f64 + message_width as f64 / 2.0,
+        label = escape_xml(label),
+        message = escape_xml(message),
+        color = escape_xml(color),
+    )
+}
+
+pub fn coverage_color(percentage: f64, thresholds: &[BadgeThreshold]) -> &str {
+    thresholds
+        .iter()
+        .filter(|threshold| percentage >= threshold.min)
+        .max_by(|a, b| a.min.total_cmp(&b.min))
+        .map(|threshold| threshold.color.as_str())
+        .unwrap_or(FALLBACK_COLOR)
+}
+
+// Rough width of Verdana 11px, which is good enough to size the badge without font metrics.
+fn text_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'l' | 'j' | '.' | ':' | ' ' | '!' | '|' => 4,
+            'm' | 'w' | 'M' | 'W' | '%' => 10,
+            _ => 7,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_color_of_the_highest_threshold_reached() {
+        let thresholds = [
+            BadgeThreshold {
+                min: 90.0,
+                color: "green".to_string(),
+            },
+            BadgeThreshold {
+                min: 50.0,
+                color: "orange".to_string(),
+            },
+        ];
+
+        assert_eq!(coverage_color(100.0, &thresholds), "green");
+        assert_eq!(coverage_color(90.0, &thresholds), "green");
+        assert_eq!(coverage_color(75.5, &thresholds), "orange");
+        assert_eq!(coverage_color(10.0, &thresholds), FALLBACK_COLOR);
+        assert_eq!(coverage_color(100.0, &[]), FALLBACK_COLOR);
+    }
+
+    #[test]
+    fn sizes_the_badge_to_its_text() {
+        let svg = badge("de", "100%", "#4c1");
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"65\""));
+        assert!(svg.contains("aria-label=\"de: 100%\""));
+        assert!(svg.contains("<rect x=\"24\" width=\"41\" height=\"20\" fill=\"#4c1\"/>"));
+        assert!(badge("a<b", "x", "red").contains("<title>a&lt;b: x</title>"));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::diagnostics::{Severity, display_path};
+use crate::validation::{Issue, Validation};
+
+// See https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions
+pub fn validation_report(validation: &Validation) -> String {
+    validation
+        .issues
+        .iter()
+        .map(workflow_command)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn workflow_command(issue: &Issue) -> String {
+    let command = match issue.severity() {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+
+    let mut properties = Vec::new();
+
+    match &issue.diagnostic.location {
+        Some(location) => {
+            properties.push(format!(
+                "file={}",
+                escape_property(&display_path(&location.file))
+            ));
+            properties.push(format!("line={}", location.line));
+            properties.push(format!("col={}", location.column));
+            properties.push(format!("endColumn={}", location.column + location.length));
+        }
+        None => properties.push(format!(
+            "file={}",
+            escape_property(&display_path(&issue.file))
+        )),
+    }
+
+    properties.push(format!(
+        "title={}",
+        escape_property(&format!("{} ({})", issue.kind.name(), issue.locale))
+    ));
+
+    format!(
+        "::{} {}::{}",
+        command,
+        properties.join(","),
+        escape_data(&issue.diagnostic.message)
+    )
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, value_location};
+    use crate::diagnostics::Diagnostic;
+    use crate::validation::IssueKind;
+
+    #[test]
+    fn writes_workflow_commands() {
+        let de = locale_file("de", "{\n  a: 'Hallo  Welt'\n}");
+        let location = value_location(&de, &translation(&de, "a"), 5, 2);
+        let double_space = issue(
+            IssueKind::DoubleSpace,
+            &de,
+            "a",
+            "100% wrong,\nreally".to_string(),
+            location,
+        );
+        let mut missing = double_space.clone();
+        missing.kind = IssueKind::MissingKey;
+        missing.diagnostic = Diagnostic::new(Severity::Error, "missing".to_string(), None);
+
+        assert_eq!(
+            workflow_command(&double_space),
+            "::warning file=de.json5,line=2,col=12,endColumn=14,title=double_space (de)::\
+             100%25 wrong,%0Areally"
+        );
+        assert_eq!(
+            workflow_command(&missing),
+            "::error file=de.json5,title=missing_key (de)::missing"
+        );
+    }
+
+    #[test]
+    fn escapes_properties() {
+        assert_eq!(escape_property("C:\\a,b%\n"), "C%3A\\a%2Cb%25%0A");
+    }
+}
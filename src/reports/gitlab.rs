@@ -0,0 +1,77 @@
+use crate::diagnostics::{Severity, display_path};
+use crate::validation::{Issue, Validation};
+use serde_json::{Value, json};
+
+// See https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format
+pub fn validation_report(validation: &Validation) -> String {
+    let issues = validation
+        .issues
+        .iter()
+        .map(code_quality_issue)
+        .collect::<Vec<_>>();
+
+    serde_json::to_string_pretty(&issues).unwrap()
+}
+
+fn code_quality_issue(issue: &Issue) -> Value {
+    let (path, line) = match &issue.diagnostic.location {
+        Some(location) => (display_path(&location.file), location.line),
+        None => (display_path(&issue.file), 1),
+    };
+
+    json!({
+        "description": issue.diagnostic.message,
+        "check_name": issue.kind.name(),
+        "fingerprint": issue.fingerprint(),
+        "severity": match issue.severity() {
+            Severity::Error => "major",
+            Severity::Warning => "minor",
+        },
+        "location": {
+            "path": path.replace('\\', "/"),
+            "lines": { "begin": line },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, value_location};
+    use crate::diagnostics::Diagnostic;
+    use crate::validation::IssueKind;
+
+    #[test]
+    fn writes_code_quality_issues() {
+        let de = locale_file("de", "{\n  a: 'Hallo  Welt'\n}");
+        let location = value_location(&de, &translation(&de, "a"), 5, 2);
+        let double_space = issue(
+            IssueKind::DoubleSpace,
+            &de,
+            "a",
+            "double".to_string(),
+            location,
+        );
+        let mut missing = double_space.clone();
+        missing.kind = IssueKind::MissingKey;
+        missing.diagnostic = Diagnostic::new(Severity::Error, "missing".to_string(), None);
+
+        assert_eq!(
+            code_quality_issue(&double_space),
+            json!({
+                "description": "double",
+                "check_name": "double_space",
+                "fingerprint": double_space.fingerprint(),
+                "severity": "minor",
+                "location": { "path": "de.json5", "lines": { "begin": 2 } },
+            })
+        );
+        assert_eq!(code_quality_issue(&missing)["severity"], "major");
+        assert_eq!(
+            code_quality_issue(&missing)["location"]["lines"]["begin"],
+            1
+        );
+        assert_ne!(missing.fingerprint(), double_space.fingerprint());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::backup;
+use crate::diagnostics::Severity;
+use crate::document::Node;
+use crate::reports::escape_xml;
+use crate::validation::{LocaleFile, Validation};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+h1 { margin-bottom: 0; }
+.muted { color: #656d76; }
+.locales { display: grid; grid-template-columns: repeat(auto-fill, minmax(16rem, 1fr)); gap: 1rem; margin: 1.5rem 0; }
+.locale { border: 1px solid #d0d7de; border-radius: 6px; padding: 0.75rem 1rem; }
+.bar { background: #eaeef2; border-radius: 4px; height: 0.6rem; overflow: hidden; margin: 0.5rem 0; }
+.bar > div { background: #2da44e; height: 100%; }
+.bar.low > div { background: #cf222e; }
+.bar.mid > div { background: #d4a72c; }
+details { margin: 0.5rem 0; }
+ul.issues { font-family: ui-monospace, monospace; font-size: 0.85rem; }
+.error { color: #cf222e; }
+.warning { color: #9a6700; }
+input[type=search] { width: 100%; max-width: 30rem; padding: 0.4rem; margin: 0.5rem 0; }
+table { border-collapse: collapse; width: 100%; font-size: 0.9rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.3rem 0.5rem; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; position: sticky; top: 0; }
+td.key { font-family: ui-monospace, monospace; white-space: nowrap; }
+td.missing { background: #ffebe9; }
+td.empty { background: #fff8c5; }
+td.issue { background: #fff1e5; }
+td.extra { background: #ddf4ff; }
+"#;
+
+const SCRIPT: &str = r#"
+const search = document.getElementById('search');
+const problems = document.getElementById('problems');
+function filter() {
+  const query = search.value.toLowerCase();
+  for (const row of document.querySelectorAll('#keys tbody tr')) {
+    const matches = row.textContent.toLowerCase().includes(query);
+    const visible = matches && (!problems.checked || row.dataset.problem === 'true');
+    row.style.display = visible ? '' : 'none';
+  }
+}
+search.addEventListener('input', filter);
+problems.addEventListener('change', filter);
+"#;
+
+pub fn validation_report(validation: &Validation) -> String {
+    let generated_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| backup::format_timestamp(now.as_secs(), "-", " ", ":"))
+        .unwrap_or_default();
+
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>Translation status</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>Translation status</h1>\n\
+         <p class=\"muted\">Default locale <code>{}</code> with {} keys, {} errors and {} warnings. Generated {} UTC by {} {}.</p>\n",
+        STYLE,
+        escape_xml(&validation.default_locale.locale),
+        validation.default_locale.keys.len(),
+        validation.error_count(),
+        validation.warning_count(),
+        generated_at,
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+    );
+
+    html.push_str(&locales_section(validation));
+    html.push_str(&issues_section(validation));
+    html.push_str(&keys_section(validation));
+    html.push_str(&format!("<script>{}</script>\n</body>\n</html>\n", SCRIPT));
+
+    html
+}
+
+fn locales_section(validation: &Validation) -> String {
+    let mut html = String::from("<div class=\"locales\">\n");
+
+    for locale in &validation.locales {
+        let coverage = validation.coverage(locale);
+        let percentage = coverage.percentage();
+        let class = if percentage < 50.0 {
+            "bar low"
+        } else if percentage < 90.0 {
+            "bar mid"
+        } else {
+            "bar"
+        };
+
+        html.push_str(&format!(
+            "<div class=\"locale\"><strong>{}</strong> {:.1}%\
+             <div class=\"{}\"><div style=\"width: {:.1}%\"></div></div>\
+             <span class=\"muted\">{} of {} translated, {} missing, {} empty, {} extra</span></div>\n",
+            escape_xml(&locale.locale),
+            percentage,
+            class,
+            percentage,
+            coverage.translated,
+            coverage.total,
+            coverage.missing.len(),
+            coverage.empty.len(),
+            coverage.extra.len()
+        ));
+    }
+
+    html.push_str("</div>\n");
+    html
+}
+
+fn issues_section(validation: &Validation) -> String {
+    let mut html = String::from("<h2>Issues</h2>\n");
+
+    if validation.issues.is_empty() {
+        html.push_str("<p>No issues found.</p>\n");
+        return html;
+    }
+
+    for locale in &validation.locales {
+        let issues = validation.locale_issues(locale);
+        if issues.is_empty() {
+            continue;
+        }
+
+        html.push_str(&format!(
+            "<details open><summary><strong>{}</strong> ({} issues)</summary>\n<ul class=\"issues\">\n",
+            escape_xml(&locale.locale),
+            issues.len()
+        ));
+
+        for issue in issues {
+            let class = match issue.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            let location = issue
+                .diagnostic
+                .location
+                .as_ref()
+                .map(|location| {
+                    format!(
+                        " <span class=\"muted\">{}</span>",
+                        escape_xml(&location.to_string())
+                    )
+                })
+                .unwrap_or_default();
+
+            html.push_str(&format!(
+                "<li><span class=\"{}\">{}</span> {}{}</li>\n",
+                class,
+                issue.kind.name(),
+                escape_xml(&issue.diagnostic.message),
+                location
+            ));
+        }
+
+        html.push_str("</ul>\n</details>\n");
+    }
+
+    html
+}
+
+fn keys_section(validation: &Validation) -> String {
+    let mut keys = validation.default_locale.keys.clone();
+    for locale in &validation.locales {
+        for key in &locale.keys {
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+    }
+
+    let mut html = String::from(
+        "<h2>Keys</h2>\n<input type=\"search\" id=\"search\" placeholder=\"Search keys and values\">\n\
+         <label><input type=\"checkbox\" id=\"problems\"> Only keys with problems</label>\n\
+         <table id=\"keys\">\n<thead><tr><th>Key</th>",
+    );
+
+    for locale in &validation.locales {
+        html.push_str(&format!("<th>{}</th>", escape_xml(&locale.locale)));
+    }
+
+    html.push_str("</tr></thead>\n<tbody>\n");
+
+    for key in &keys {
+        let mut problem = false;
+        let mut cells = String::new();
+
+        for locale in &validation.locales {
+            let (class, content) = key_cell(validation, locale, key);
+            problem |= !class.is_empty();
+            cells.push_str(&format!("<td class=\"{}\">{}</td>", class, content));
+        }
+
+        html.push_str(&format!(
+            "<tr data-problem=\"{}\"><td class=\"key\">{}</td>{}</tr>\n",
+            problem,
+            escape_xml(key),
+            cells
+        ));
+    }
+
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+fn key_cell(validation: &Validation, locale: &LocaleFile, key: &str) -> (&'static str, String) {
+    let Some(member) = locale.document.find(key) else {
+        return ("missing", "<em>missing</em>".to_string());
+    };
+
+    let value = match &member.value {
+        Node::String { value, .. } => value.clone(),
+        node => node.to_value().to_string(),
+    };
+
+    let has_issue = validation
+        .issues
+        .iter()
+        .any(|issue| issue.file == locale.file && issue.key == key);
+
+    let class = if value.is_empty() {
+        "empty"
+    } else if has_issue {
+        "issue"
+    } else if !validation.default_locale.keys.iter().any(|k| k == key) {
+        "extra"
+    } else {
+        ""
+    };
+
+    (class, escape_xml(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, value_location};
+    use crate::validation::IssueKind;
+
+    #[test]
+    fn shows_coverage_issues_and_keys() {
+        let en = locale_file("en", "{a: 'A', b: 'B', c: 'C & D'}");
+        let de = locale_file("de", "{a: 'A', b: '', x: '<X>'}");
+        let location = value_location(&de, &translation(&de, "a"), 0, 1);
+        let untranslated = issue(
+            IssueKind::UntranslatedValue,
+            &de,
+            "a",
+            "same as <en>".to_string(),
+            location,
+        );
+        let validation = Validation {
+            default_locale: en,
+            locales: vec![de],
+            issues: vec![untranslated],
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        let html = validation_report(&validation);
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("with 3 keys, 0 errors and 1 warnings."));
+        assert!(html.contains("<strong>de</strong> 33.3%<div class=\"bar low\">"));
+        assert!(html.contains("1 of 3 translated, 1 missing, 1 empty, 1 extra"));
+        assert!(html.contains(
+            "<li><span class=\"warning\">untranslated_value</span> same as &lt;en&gt; \
+             <span class=\"muted\">de.json5:1:6</span></li>"
+        ));
+
+        assert!(html.contains(
+            "<tr data-problem=\"true\"><td class=\"key\">a</td><td class=\"issue\">A</td></tr>"
+        ));
+        assert!(html.contains("<td class=\"key\">b</td><td class=\"empty\"></td>"));
+        assert!(
+            html.contains("<td class=\"key\">c</td><td class=\"missing\"><em>missing</em></td>")
+        );
+        assert!(html.contains("<td class=\"key\">x</td><td class=\"extra\">&lt;X&gt;</td>"));
+    }
+
+    #[test]
+    fn says_when_there_are_no_issues() {
+        let en = locale_file("en", "{a: 'A'}");
+        let validation = Validation {
+            default_locale: en.clone(),
+            locales: vec![en],
+            issues: Vec::new(),
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        let html = validation_report(&validation);
+        assert!(html.contains("<p>No issues found.</p>"));
+        assert!(html.contains(
+            "<tr data-problem=\"false\"><td class=\"key\">a</td><td class=\"\">A</td></tr>"
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::backup::{self, Snapshot};
+use crate::baseline::BaselineEntry;
+use crate::commands::CommandError;
+use crate::diagnostics::{Location, Severity, display_path};
+use crate::validation::{Issue, IssueKind, Validation};
+use serde::Serialize;
+
+// Bump this when making a breaking change to any of the records below.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct ValidationReport<'a> {
+    version: u32,
+    command: &'static str,
+    success: bool,
+    default_locale: &'a str,
+    summary: Summary,
+    issues: Vec<IssueRecord>,
+    baseline: Option<BaselineRecord>,
+}
+
+#[derive(Serialize)]
+struct BaselineRecord {
+    known: usize,
+    fixed: Vec<BaselineEntry>,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    errors: usize,
+    warnings: usize,
+    suppressed: usize,
+}
+
+#[derive(Serialize)]
+struct IssueRecord {
+    #[serde(rename = "type")]
+    kind: IssueKind,
+    severity: Severity,
+    locale: String,
+    file: String,
+    key: String,
+    message: String,
+    location: Option<LocationRecord>,
+    related: Vec<RelatedRecord>,
+}
+
+#[derive(Serialize)]
+struct LocationRecord {
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Serialize)]
+struct RelatedRecord {
+    message: String,
+    location: LocationRecord,
+}
+
+#[derive(Serialize)]
+struct ErrorReport {
+    version: u32,
+    error: ErrorRecord,
+}
+
+#[derive(Serialize)]
+struct ErrorRecord {
+    kind: &'static str,
+    message: String,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    exit_code: i32,
+}
+
+#[derive(Serialize)]
+struct SnapshotsReport {
+    version: u32,
+    command: &'static str,
+    snapshots: Vec<SnapshotRecord>,
+}
+
+#[derive(Serialize)]
+struct SnapshotRecord {
+    id: String,
+    created_at: String,
+    command: String,
+    files: Vec<String>,
+}
+
+impl From<&Location> for LocationRecord {
+    fn from(location: &Location) -> Self {
+        Self {
+            file: display_path(&location.file),
+            line: location.line,
+            column: location.column,
+        }
+    }
+}
+
+impl From<&Issue> for IssueRecord {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            kind: issue.kind,
+            severity: issue.severity(),
+            locale: issue.locale.clone(),
+            file: display_path(&issue.file),
+            key: issue.key.clone(),
+            message: issue.diagnostic.message.clone(),
+            location: issue.diagnostic.location.as_ref().map(LocationRecord::from),
+            related: issue
+                .diagnostic
+                .notes
+                .iter()
+                .map(|(message, location)| RelatedRecord {
+                    message: message.clone(),
+                    location: location.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn validation_report(validation: &Validation) -> String {
+    let report = ValidationReport {
+        version: SCHEMA_VERSION,
+        command: "validate",
+        success: validation.error_count() == 0,
+        default_locale: &validation.default_locale.locale,
+        summary: Summary {
+            errors: validation.error_count(),
+            warnings: validation.warning_count(),
+            suppressed: validation.suppressed.len(),
+        },
+        issues: validation.issues.iter().map(IssueRecord::from).collect(),
+        baseline: validation
+            .baseline
+            .as_ref()
+            .map(|comparison| BaselineRecord {
+                known: comparison.known.len(),
+                fixed: comparison.fixed.clone(),
+            }),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap()
+}
+
+pub fn error_report(error: &CommandError) -> String {
+    let location = error.location();
+    let report = ErrorReport {
+        version: SCHEMA_VERSION,
+        error: ErrorRecord {
+            kind: error.kind(),
+            message: error.message(),
+            file: location.map(|location| display_path(&location.file)),
+            line: location.map(|location| location.line),
+            column: location.map(|location| location.column),
+            exit_code: error.exit_code(),
+        },
+    };
+
+    serde_json::to_string_pretty(&report).unwrap()
+}
+
+pub fn snapshots_report(snapshots: &[Snapshot]) -> String {
+    let report = SnapshotsReport {
+        version: SCHEMA_VERSION,
+        command: "restore",
+        snapshots: snapshots
+            .iter()
+            .map(|snapshot| SnapshotRecord {
+                id: snapshot.id.clone(),
+                created_at: format!(
+                    "{}Z",
+                    backup::format_timestamp(snapshot.created_at, "-", "T", ":")
+                ),
+                command: snapshot.command.clone(),
+                files: snapshot.files.clone(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{untranslated, value_location};
+    use crate::diagnostics::Diagnostic;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn reports_issues_with_their_locations() {
+        let en = locale_file("en", "{a: 'Save changes'}");
+        let de = locale_file("de", "{\n  a: 'Save changes'\n}");
+        let issue = untranslated::check(
+            &en,
+            &de,
+            &translation(&en, "a"),
+            &translation(&de, "a"),
+            &untranslated::UntranslatedValues::default(),
+        )
+        .unwrap();
+        let validation = Validation {
+            default_locale: en,
+            locales: vec![de],
+            issues: vec![issue],
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        let report = serde_json::from_str::<Value>(&validation_report(&validation)).unwrap();
+        assert_eq!(report["version"], SCHEMA_VERSION);
+        assert_eq!(report["command"], "validate");
+        assert_eq!(report["success"], true);
+        assert_eq!(report["default_locale"], "en");
+        assert_eq!(
+            report["summary"],
+            json!({ "errors": 0, "warnings": 1, "suppressed": 0 })
+        );
+        assert_eq!(report["baseline"], Value::Null);
+
+        let issue = &report["issues"][0];
+        assert_eq!(issue["type"], "untranslated_value");
+        assert_eq!(issue["severity"], "warning");
+        assert_eq!(issue["locale"], "de");
+        assert_eq!(issue["file"], "de.json5");
+        assert_eq!(
+            issue["location"],
+            json!({ "file": "de.json5", "line": 2, "column": 6 })
+        );
+        assert_eq!(issue["related"][0]["location"]["file"], "en.json5");
+    }
+
+    #[test]
+    fn reports_errors() {
+        let de = locale_file("de", "{\n  a: 'A'\n}");
+        let location = value_location(&de, &translation(&de, "a"), 0, 1);
+        let error = CommandError::Parse(Box::new(Diagnostic::new(
+            Severity::Error,
+            "Expected ','".to_string(),
+            Some(location),
+        )));
+
+        let report = serde_json::from_str::<Value>(&error_report(&error)).unwrap();
+        assert_eq!(
+            report,
+            json!({
+                "version": SCHEMA_VERSION,
+                "error": {
+                    "kind": "parse",
+                    "message": "Expected ','",
+                    "file": "de.json5",
+                    "line": 2,
+                    "column": 7,
+                    "exit_code": 5,
+                }
+            })
+        );
+
+        let error = CommandError::Config("No config found".to_string());
+        let report = serde_json::from_str::<Value>(&error_report(&error)).unwrap();
+        assert_eq!(report["error"]["kind"], "config");
+        assert_eq!(report["error"]["file"], Value::Null);
+        assert_eq!(report["error"]["exit_code"], 3);
+    }
+}
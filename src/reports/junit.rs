@@ -0,0 +1,196 @@
+use crate::diagnostics::{Severity, display_path};
+use crate::reports::escape_xml;
+use crate::validation::{Issue, LocaleFile, Validation};
+
+pub fn validation_report(validation: &Validation) -> String {
+    let suites = validation
+        .locales
+        .iter()
+        .map(|locale| test_suite(validation, locale))
+        .collect::<Vec<_>>();
+
+    let tests = suites.iter().map(|suite| suite.tests).sum::<usize>();
+    let failures = suites.iter().map(|suite| suite.failures).sum::<usize>();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+        env!("CARGO_PKG_NAME"),
+        tests,
+        failures
+    ));
+
+    for suite in suites {
+        xml.push_str(&suite.xml);
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+struct TestSuite {
+    tests: usize,
+    failures: usize,
+    xml: String,
+}
+
+// Every key of the default locale is a test case, plus any other keys that have issues.
+fn test_suite(validation: &Validation, locale: &LocaleFile) -> TestSuite {
+    let issues = validation.locale_issues(locale);
+
+    let mut keys = validation.default_locale.keys.clone();
+    for issue in &issues {
+        if !keys.contains(&issue.key) {
+            keys.push(issue.key.clone());
+        }
+    }
+
+    let file = escape_xml(&display_path(&locale.file));
+    let mut failures = 0;
+    let mut cases = String::new();
+
+    for key in &keys {
+        let key_issues = issues
+            .iter()
+            .copied()
+            .filter(|issue| &issue.key == key)
+            .collect::<Vec<_>>();
+        let errors = key_issues
+            .iter()
+            .copied()
+            .filter(|issue| issue.severity() == Severity::Error)
+            .collect::<Vec<_>>();
+        let warnings = key_issues
+            .iter()
+            .copied()
+            .filter(|issue| issue.severity() == Severity::Warning)
+            .collect::<Vec<_>>();
+
+        let name = key_issues
+            .first()
+            .map(|issue| issue.subject())
+            .unwrap_or(key);
+
+        cases.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\" time=\"0\"",
+            escape_xml(name),
+            escape_xml(&locale.locale),
+            file
+        ));
+
+        if errors.is_empty() && warnings.is_empty() {
+            cases.push_str("/>\n");
+            continue;
+        }
+
+        cases.push_str(">\n");
+
+        if let Some(first) = errors.first() {
+            failures += 1;
+            cases.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                first.kind.name(),
+                escape_xml(&first.diagnostic.message),
+                escape_xml(&details(&errors))
+            ));
+        }
+
+        if !warnings.is_empty() {
+            cases.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                escape_xml(&details(&warnings))
+            ));
+        }
+
+        cases.push_str("    </testcase>\n");
+    }
+
+    let xml = format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" file=\"{}\">\n{}  </testsuite>\n",
+        escape_xml(&locale.locale),
+        keys.len(),
+        failures,
+        file,
+        cases
+    );
+
+    TestSuite {
+        tests: keys.len(),
+        failures,
+        xml,
+    }
+}
+
+fn details(issues: &[&Issue]) -> String {
+    issues
+        .iter()
+        .map(|issue| issue.diagnostic.to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, value_location};
+    use crate::validation::IssueKind;
+
+    fn test_issue(kind: IssueKind, locale: &LocaleFile, key: &str, message: &str) -> Issue {
+        let location = value_location(locale, &translation(locale, key), 0, 1);
+        issue(kind, locale, key, message.to_string(), location)
+    }
+
+    #[test]
+    fn writes_a_test_suite_per_locale() {
+        let en = locale_file("en", "{a: 'A', b: 'B', c: 'C'}");
+        let de = locale_file("de", "{a: '', b: 'B', c: 'C', d: 'D'}");
+        let fr = locale_file("fr", "{a: 'A', b: 'B', c: 'C'}");
+        let issues = vec![
+            test_issue(IssueKind::EmptyValue, &de, "a", "a & <b>"),
+            test_issue(IssueKind::DoubleSpace, &de, "a", "double"),
+            test_issue(IssueKind::UntranslatedValue, &de, "c", "same"),
+            test_issue(IssueKind::RemovedKey, &de, "d", "removed"),
+        ];
+        let validation = Validation {
+            default_locale: en.clone(),
+            locales: vec![de, fr],
+            issues,
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        let xml = validation_report(&validation);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites "));
+        assert!(xml.contains("tests=\"7\" failures=\"2\" errors=\"0\">"));
+        assert!(xml.ends_with("</testsuites>\n"));
+        assert!(xml.contains(
+            "  <testsuite name=\"de\" tests=\"4\" failures=\"2\" errors=\"0\" skipped=\"0\" \
+             file=\"de.json5\">"
+        ));
+        assert!(xml.contains(
+            "  <testsuite name=\"fr\" tests=\"3\" failures=\"0\" errors=\"0\" skipped=\"0\" \
+             file=\"fr.json5\">"
+        ));
+
+        assert!(xml.contains(
+            "<testcase name=\"a\" classname=\"de\" file=\"de.json5\" time=\"0\">\n      \
+             <failure type=\"empty_value\" message=\"a &amp; &lt;b&gt;\">"
+        ));
+        assert!(
+            xml.contains("<testcase name=\"b\" classname=\"de\" file=\"de.json5\" time=\"0\"/>")
+        );
+        assert!(xml.contains("<testcase name=\"d\" classname=\"de\""));
+        assert!(xml.contains("<failure type=\"removed_key\""));
+
+        // Warnings are shown as output without failing the test case.
+        let case = &xml[xml.find("<testcase name=\"c\" classname=\"de\"").unwrap()..];
+        let case = &case[..case.find("</testcase>").unwrap()];
+        assert!(!case.contains("<failure"));
+        assert!(case.contains("<system-out>warning: same"));
+
+        let case = &xml[xml.find("<testcase name=\"a\" classname=\"de\"").unwrap()..];
+        let case = &case[..case.find("</testcase>").unwrap()];
+        assert!(case.contains("<system-out>warning: double"));
+    }
+}
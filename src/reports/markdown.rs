@@ -0,0 +1,159 @@
+use crate::diagnostics::Severity;
+use crate::validation::Validation;
+
+pub fn validation_report(validation: &Validation) -> String {
+    let mut markdown = String::from("## Translation status\n\n");
+
+    markdown.push_str(&format!(
+        "Compared to the default locale `{}` with {} keys: {} errors, {} warnings, {} suppressed.\n\n",
+        validation.default_locale.locale,
+        validation.default_locale.keys.len(),
+        validation.error_count(),
+        validation.warning_count(),
+        validation.suppressed.len()
+    ));
+
+    if let Some(comparison) = &validation.baseline {
+        markdown.push_str(&format!(
+            "{} known issues are in the baseline, {} of its issues are fixed.\n\n",
+            comparison.known.len(),
+            comparison.fixed.len()
+        ));
+    }
+
+    markdown.push_str("| Locale | Coverage | Missing | Empty | Extra | Issues |\n");
+    markdown.push_str("|--------|---------:|--------:|------:|------:|-------:|\n");
+
+    for locale in &validation.locales {
+        let coverage = validation.coverage(locale);
+        let issues = validation.locale_issues(locale);
+        let status = if issues
+            .iter()
+            .any(|issue| issue.severity() == Severity::Error)
+        {
+            "❌"
+        } else {
+            "✅"
+        };
+
+        markdown.push_str(&format!(
+            "| {} `{}` | {:.1}% ({}/{}) | {} | {} | {} | {} |\n",
+            status,
+            locale.locale,
+            coverage.percentage(),
+            coverage.translated,
+            coverage.total,
+            coverage.missing.len(),
+            coverage.empty.len(),
+            coverage.extra.len(),
+            issues.len()
+        ));
+    }
+
+    for locale in &validation.locales {
+        let coverage = validation.coverage(locale);
+        let issues = validation.locale_issues(locale);
+
+        if issues.is_empty() && coverage.empty.is_empty() && coverage.extra.is_empty() {
+            continue;
+        }
+
+        markdown.push_str(&format!(
+            "\n<details>\n<summary><code>{}</code>: {} issues, {} empty, {} extra keys</summary>\n\n",
+            locale.locale,
+            issues.len(),
+            coverage.empty.len(),
+            coverage.extra.len()
+        ));
+
+        for issue in issues {
+            let icon = match issue.severity() {
+                Severity::Error => "❌",
+                Severity::Warning => "⚠️",
+            };
+            let location = issue
+                .diagnostic
+                .location
+                .as_ref()
+                .map(|location| format!(" ({})", location))
+                .unwrap_or_default();
+
+            markdown.push_str(&format!(
+                "- {} `{}`: {}{}\n",
+                icon,
+                issue.subject(),
+                escape(&issue.diagnostic.message),
+                escape(&location)
+            ));
+        }
+
+        for (label, keys) in [("Empty", &coverage.empty), ("Extra", &coverage.extra)] {
+            if keys.is_empty() {
+                continue;
+            }
+
+            let keys = keys
+                .iter()
+                .map(|key| format!("`{}`", key))
+                .collect::<Vec<_>>()
+                .join(", ");
+            markdown.push_str(&format!("- {} keys: {}\n", label, keys));
+        }
+
+        markdown.push_str("\n</details>\n");
+    }
+
+    markdown
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, value_location};
+    use crate::validation::IssueKind;
+
+    #[test]
+    fn summarizes_each_locale() {
+        let en = locale_file("en", "{a: 'A', b: 'B', c: 'C', d: 'D'}");
+        let de = locale_file("de", "{a: 'A', b: '', x: 'X'}");
+        let fr = locale_file("fr", "{a: 'A', b: 'B', c: 'C', d: 'D'}");
+        let location = value_location(&de, &translation(&de, "a"), 0, 1);
+        let untranslated = issue(
+            IssueKind::UntranslatedValue,
+            &de,
+            "a",
+            "same as <en>".to_string(),
+            location,
+        );
+        let validation = Validation {
+            default_locale: en,
+            locales: vec![de, fr],
+            issues: vec![untranslated],
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        let markdown = validation_report(&validation);
+        assert!(markdown.starts_with("## Translation status\n\n"));
+        assert!(markdown.contains(
+            "Compared to the default locale `en` with 4 keys: 0 errors, 1 warnings, 0 suppressed."
+        ));
+        assert!(markdown.contains("| ✅ `de` | 25.0% (1/4) | 2 | 1 | 1 | 1 |\n"));
+        assert!(markdown.contains("| ✅ `fr` | 100.0% (4/4) | 0 | 0 | 0 | 0 |\n"));
+        assert!(
+            markdown
+                .contains("<summary><code>de</code>: 1 issues, 1 empty, 1 extra keys</summary>")
+        );
+        assert!(markdown.contains("- ⚠️ `a`: same as &lt;en&gt; (de.json5:1:6)\n"));
+        assert!(markdown.contains("- Empty keys: `b`\n- Extra keys: `x`\n"));
+        assert!(!markdown.contains("<code>fr</code>"));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::validation::Validation;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub mod badge;
+pub mod github;
+pub mod gitlab;
+pub mod html;
+pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod sarif;
+
+pub const GITLAB_REPORT_PATH: &str = "gl-code-quality-report.json";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Sarif,
+    Junit,
+    Github,
+    Gitlab,
+    Markdown,
+}
+
+impl ReportFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReportFormat::Sarif => "sarif",
+            ReportFormat::Junit => "junit",
+            ReportFormat::Github => "github",
+            ReportFormat::Gitlab => "gitlab",
+            ReportFormat::Markdown => "markdown",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportTarget {
+    pub format: ReportFormat,
+    pub path: Option<PathBuf>,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sarif" => Ok(ReportFormat::Sarif),
+            "junit" => Ok(ReportFormat::Junit),
+            "github" => Ok(ReportFormat::Github),
+            "gitlab" => Ok(ReportFormat::Gitlab),
+            "markdown" => Ok(ReportFormat::Markdown),
+            _ => Err(format!(
+                "Unknown report format '{}', expected one of: sarif, junit, github, gitlab, markdown",
+                s
+            )),
+        }
+    }
+}
+
+impl FromStr for ReportTarget {
+    type Err = String;
+
+    // Parses `<format>` or `<format>=<path>`, without a path the report is printed to stdout.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, path) = match s.split_once('=') {
+            Some((format, path)) => (format, Some(PathBuf::from(path))),
+            None => (s, None),
+        };
+
+        Ok(ReportTarget {
+            format: format.parse()?,
+            path,
+        })
+    }
+}
+
+/// Returns the annotation reports for the CI environment we are running in, unless a
+/// report of the same format was already requested.
+pub fn detect_ci_reports(requested: &[ReportTarget], stdout_available: bool) -> Vec<ReportTarget> {
+    let mut targets = Vec::new();
+    let is_set = |name: &str| env::var(name).is_ok_and(|value| value == "true");
+    let is_requested = |format: ReportFormat| requested.iter().any(|r| r.format == format);
+
+    if stdout_available && is_set("GITHUB_ACTIONS") && !is_requested(ReportFormat::Github) {
+        targets.push(ReportTarget {
+            format: ReportFormat::Github,
+            path: None,
+        });
+    }
+
+    if is_set("GITLAB_CI") && !is_requested(ReportFormat::Gitlab) {
+        targets.push(ReportTarget {
+            format: ReportFormat::Gitlab,
+            path: Some(PathBuf::from(GITLAB_REPORT_PATH)),
+        });
+    }
+
+    targets
+}
+
+pub fn write_report(target: &ReportTarget, validation: &Validation) -> Result<(), String> {
+    let report = match target.format {
+        ReportFormat::Sarif => sarif::validation_report(validation),
+        ReportFormat::Junit => junit::validation_report(validation),
+        ReportFormat::Github => github::validation_report(validation),
+        ReportFormat::Gitlab => gitlab::validation_report(validation),
+        ReportFormat::Markdown => markdown::validation_report(validation),
+    };
+
+    match &target.path {
+        Some(path) => {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                fs::create_dir_all(parent).map_err(|err| {
+                    format!("Could not create directory {}: {}", parent.display(), err)
+                })?;
+            }
+
+            fs::write(path, report)
+                .map_err(|err| format!("Could not write report {}: {}", path.display(), err))
+        }
+        None => {
+            if !report.is_empty() {
+                println!("{}", report);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Escapes text for use in XML and HTML documents, both in content and attributes.
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Characters that are not allowed in XML 1.0 at all.
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
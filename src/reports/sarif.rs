@@ -0,0 +1,209 @@
+use crate::diagnostics::{Location, Severity, display_path};
+use crate::validation::{Issue, IssueKind, Validation};
+use serde_json::{Value, json};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+pub fn validation_report(validation: &Validation) -> String {
+    let rules = IssueKind::ALL
+        .iter()
+        .map(|kind| {
+            json!({
+                "id": kind.name(),
+                "shortDescription": { "text": kind.description() },
+                "defaultConfiguration": { "level": level(kind.default_severity()) },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results = validation.issues.iter().map(result).collect::<Vec<_>>();
+
+    let report = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    });
+
+    serde_json::to_string_pretty(&report).unwrap()
+}
+
+fn result(issue: &Issue) -> Value {
+    let rule_index = IssueKind::ALL
+        .iter()
+        .position(|kind| *kind == issue.kind)
+        .unwrap();
+
+    let mut result = json!({
+        "ruleId": issue.kind.name(),
+        "ruleIndex": rule_index,
+        "level": level(issue.severity()),
+        "message": { "text": issue.diagnostic.message },
+        "locations": [
+            physical_location(issue.diagnostic.location.as_ref(), issue),
+        ],
+    });
+
+    if !issue.diagnostic.notes.is_empty() {
+        result["relatedLocations"] = issue
+            .diagnostic
+            .notes
+            .iter()
+            .enumerate()
+            .map(|(id, (message, location))| {
+                let mut related = physical_location(Some(location), issue);
+                related["id"] = json!(id);
+                related["message"] = json!({ "text": message });
+                related
+            })
+            .collect();
+    }
+
+    result
+}
+
+fn physical_location(location: Option<&Location>, issue: &Issue) -> Value {
+    match location {
+        Some(location) => json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri(&display_path(&location.file)) },
+                "region": {
+                    "startLine": location.line,
+                    "startColumn": location.column,
+                    "endColumn": location.column + location.length,
+                    "snippet": { "text": location.source_line },
+                }
+            }
+        }),
+        // Without a position, fall back to the locale file itself.
+        None => json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri(&display_path(&issue.file)) },
+            }
+        }),
+    }
+}
+
+fn uri(path: &str) -> String {
+    path.replace('\\', "/").replace(' ', "%20")
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::{locale_file, translation};
+    use crate::checks::{issue, untranslated, value_location};
+    use crate::diagnostics::Diagnostic;
+
+    fn report(issues: Vec<Issue>) -> Value {
+        let validation = Validation {
+            default_locale: locale_file("en", "{}"),
+            locales: Vec::new(),
+            issues,
+            suppressed: Vec::new(),
+            baseline: None,
+        };
+
+        serde_json::from_str(&validation_report(&validation)).unwrap()
+    }
+
+    #[test]
+    fn describes_every_check_as_a_rule() {
+        let report = report(Vec::new());
+        let run = &report["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+
+        assert_eq!(report["version"], "2.1.0");
+        assert_eq!(report["$schema"], SARIF_SCHEMA);
+        assert_eq!(run["tool"]["driver"]["name"], env!("CARGO_PKG_NAME"));
+        assert_eq!(rules.len(), IssueKind::ALL.len());
+        assert_eq!(rules[0]["id"], IssueKind::ALL[0].name());
+        assert!(rules.iter().any(|rule| rule["id"] == "untranslated_value"
+            && rule["defaultConfiguration"]["level"] == "warning"));
+        assert_eq!(run["results"], json!([]));
+    }
+
+    #[test]
+    fn reports_issues_with_their_locations() {
+        let en = locale_file("en", "{\n  a: 'Save changes'\n}");
+        let de = locale_file("de", "{\n  b: 'B',\n  a: 'Save changes'\n}");
+        let default = translation(&en, "a");
+        let value = translation(&de, "a");
+        let untranslated = untranslated::check(
+            &en,
+            &de,
+            &default,
+            &value,
+            &untranslated::UntranslatedValues::default(),
+        )
+        .unwrap();
+        let empty = issue(
+            IssueKind::EmptyValue,
+            &de,
+            "b",
+            "empty".to_string(),
+            value_location(&de, &translation(&de, "b"), 0, 1),
+        );
+        let mut missing = empty.clone();
+        missing.kind = IssueKind::MissingKey;
+        missing.diagnostic = Diagnostic::new(Severity::Error, "missing".to_string(), None);
+
+        let report = report(vec![untranslated, empty, missing]);
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+
+        let result = &results[0];
+        let index = IssueKind::ALL
+            .iter()
+            .position(|kind| *kind == IssueKind::UntranslatedValue)
+            .unwrap();
+        assert_eq!(result["ruleId"], "untranslated_value");
+        assert_eq!(result["ruleIndex"], index);
+        assert_eq!(result["level"], "warning");
+
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "de.json5");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 6);
+        assert_eq!(location["region"]["snippet"]["text"], "  a: 'Save changes'");
+
+        let related = &result["relatedLocations"][0];
+        assert_eq!(related["id"], 0);
+        assert_eq!(
+            related["physicalLocation"]["artifactLocation"]["uri"],
+            "en.json5"
+        );
+        assert_eq!(related["physicalLocation"]["region"]["startLine"], 2);
+
+        assert_eq!(results[1]["level"], "error");
+        assert!(results[1].get("relatedLocations").is_none());
+        assert_eq!(
+            results[2]["locations"][0],
+            json!({ "physicalLocation": { "artifactLocation": { "uri": "de.json5" } } })
+        );
+    }
+
+    #[test]
+    fn escapes_uris() {
+        assert_eq!(
+            uri("i18n\\my locales\\de.json"),
+            "i18n/my%20locales/de.json"
+        );
+    }
+}
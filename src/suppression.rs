@@ -0,0 +1,261 @@
+use crate::checks::issue;
+use crate::diagnostics::Location;
+use crate::document::{Document, Span};
+use crate::validation::{Issue, IssueKind, LocaleFile, file_name};
+use serde::{Deserialize, Serialize};
+
+const MARKER: &str = "tt-ignore";
+
+/// Suppresses the issues of `checks` for the keys matching one of the `keys` globs, in the
+/// given `locales`. Leaving out any of them matches everything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suppression {
+    #[serde(default)]
+    pub keys: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<IssueKind>,
+    #[serde(default)]
+    pub locales: Vec<String>,
+}
+
+impl Suppression {
+    pub fn matches(&self, issue: &Issue) -> bool {
+        (self.checks.is_empty() || self.checks.contains(&issue.kind))
+            && (self.locales.is_empty() || self.locales.contains(&issue.locale))
+            && (self.keys.is_empty()
+                || self
+                    .keys
+                    .iter()
+                    .any(|pattern| glob_match(pattern, &issue.key)))
+    }
+}
+
+/// The checks a `// tt-ignore` marker suppresses.
+#[derive(Debug, PartialEq)]
+enum MarkerChecks {
+    // A bare `// tt-ignore`.
+    All,
+    // `// tt-ignore: check, check`, without the names that are not checks.
+    Only(Vec<IssueKind>),
+}
+
+/// Whether `issue` is suppressed by the config, or by a marker on its key in the locale file
+/// or the default locale file.
+pub fn is_suppressed(
+    issue: &Issue,
+    suppressions: &[Suppression],
+    default_locale: &LocaleFile,
+    locale: &LocaleFile,
+) -> bool {
+    if suppressions
+        .iter()
+        .any(|suppression| suppression.matches(issue))
+    {
+        return true;
+    }
+
+    [&locale.document, &default_locale.document]
+        .into_iter()
+        .filter_map(|document| marker_checks(document, &issue.key))
+        .any(|checks| match checks {
+            MarkerChecks::All => true,
+            MarkerChecks::Only(kinds) => kinds.contains(&issue.kind),
+        })
+}
+
+fn issue_kind(name: &str) -> Option<IssueKind> {
+    IssueKind::ALL.into_iter().find(|kind| kind.name() == name)
+}
+
+/// Reads a `// tt-ignore` or `// tt-ignore: check, check` comment above `key`.
+fn marker_checks(document: &Document, key: &str) -> Option<MarkerChecks> {
+    let marker = document.marker(key, MARKER)?;
+
+    if marker.is_empty() {
+        return Some(MarkerChecks::All);
+    }
+
+    Some(MarkerChecks::Only(
+        marker
+            .split(',')
+            .filter_map(|name| issue_kind(name.trim()))
+            .collect(),
+    ))
+}
+
+/// Reports the names in the `// tt-ignore` markers of `locale` that are not checks, as a typo
+/// would otherwise leave the issue it was meant for unsuppressed without notice.
+pub fn check_markers(locale: &LocaleFile) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let source = &locale.document.source;
+
+    for key in &locale.keys {
+        let Some(marker) = locale.document.marker(key, MARKER) else {
+            continue;
+        };
+
+        for name in marker.split(',').map(str::trim) {
+            if name.is_empty() || issue_kind(name).is_some() {
+                continue;
+            }
+
+            let start = name.as_ptr() as usize - source.as_ptr() as usize;
+            issues.push(issue(
+                IssueKind::InvalidMarker,
+                locale,
+                key,
+                format!(
+                    "{} has an unknown check '{}' in the {} marker of '{}'",
+                    file_name(&locale.file),
+                    name,
+                    MARKER,
+                    key
+                ),
+                Location::in_document(
+                    &locale.file,
+                    &locale.document,
+                    Span {
+                        start,
+                        end: start + name.len(),
+                    },
+                ),
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Matches dotted keys, where `*` matches within a single segment and `**` matches across
+/// segments.
+pub fn glob_match(pattern: &str, key: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let key = key.as_bytes();
+
+    fn matches(pattern: &[u8], key: &[u8]) -> bool {
+        match pattern {
+            [] => key.is_empty(),
+            [b'*', b'*', rest @ ..] => (0..=key.len()).any(|i| matches(rest, &key[i..])),
+            [b'*', rest @ ..] => (0..=key.len())
+                .take_while(|&i| i == 0 || key[i - 1] != b'.')
+                .any(|i| matches(rest, &key[i..])),
+            [b'?', rest @ ..] => !key.is_empty() && key[0] != b'.' && matches(rest, &key[1..]),
+            [c, rest @ ..] => key.first() == Some(c) && matches(rest, &key[1..]),
+        }
+    }
+
+    matches(pattern, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::testing::locale_file;
+    use crate::diagnostics::{Diagnostic, Severity};
+
+    fn test_issue(kind: IssueKind, locale: &LocaleFile, key: &str) -> Issue {
+        Issue {
+            kind,
+            locale: locale.locale.clone(),
+            file: locale.file.clone(),
+            key: key.to_string(),
+            diagnostic: Diagnostic::new(Severity::Error, String::new(), None),
+        }
+    }
+
+    #[test]
+    fn matches_globs_by_segment() {
+        assert!(glob_match("nav.*", "nav.home"));
+        assert!(!glob_match("nav.*", "nav.menu.home"));
+        assert!(glob_match("nav.**", "nav.menu.home"));
+        assert!(glob_match("**.title", "page.about.title"));
+        assert!(glob_match("item_?", "item_1"));
+        assert!(!glob_match("item_?", "item_."));
+        assert!(!glob_match("nav", "navigation"));
+    }
+
+    #[test]
+    fn reads_markers() {
+        let locale = locale_file(
+            "de",
+            "{\n  // tt-ignore\n  a: 'A',\n  // tt-ignore: empty_value, double_space\n  b: '',\n  \
+             // tt-ignore: untranslatd\n  c: 'C',\n  d: 'D'\n}",
+        );
+        let document = &locale.document;
+
+        assert_eq!(marker_checks(document, "a"), Some(MarkerChecks::All));
+        assert_eq!(
+            marker_checks(document, "b"),
+            Some(MarkerChecks::Only(vec![
+                IssueKind::EmptyValue,
+                IssueKind::DoubleSpace
+            ]))
+        );
+        assert_eq!(
+            marker_checks(document, "c"),
+            Some(MarkerChecks::Only(Vec::new()))
+        );
+        assert_eq!(marker_checks(document, "d"), None);
+    }
+
+    #[test]
+    fn unknown_check_names_do_not_suppress_everything() {
+        let en = locale_file("en", "{c: 'C'}");
+        let de = locale_file("de", "{\n  // tt-ignore: untranslatd\n  c: 'C'\n}");
+
+        let issue = test_issue(IssueKind::UntranslatedValue, &de, "c");
+        assert!(!is_suppressed(&issue, &[], &en, &de));
+
+        let issues = check_markers(&de);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::InvalidMarker);
+        assert!(issues[0].diagnostic.message.contains("'untranslatd'"));
+    }
+
+    #[test]
+    fn suppresses_by_marker_in_the_default_locale() {
+        let en = locale_file(
+            "en",
+            "{\n  // tt-ignore: empty_value\n  a: 'A',\n  b: 'B'\n}",
+        );
+        let de = locale_file("de", "{a: '', b: ''}");
+
+        assert!(is_suppressed(
+            &test_issue(IssueKind::EmptyValue, &de, "a"),
+            &[],
+            &en,
+            &de
+        ));
+        assert!(!is_suppressed(
+            &test_issue(IssueKind::MissingKey, &de, "a"),
+            &[],
+            &en,
+            &de
+        ));
+        assert!(!is_suppressed(
+            &test_issue(IssueKind::EmptyValue, &de, "b"),
+            &[],
+            &en,
+            &de
+        ));
+    }
+
+    #[test]
+    fn suppresses_by_config() {
+        let en = locale_file("en", "{}");
+        let de = locale_file("de", "{}");
+        let suppression = Suppression {
+            keys: vec!["legal.**".to_string()],
+            checks: vec![IssueKind::UntranslatedValue],
+            locales: Vec::new(),
+        };
+
+        let suppressions = [suppression];
+
+        let issue = test_issue(IssueKind::UntranslatedValue, &de, "legal.terms.title");
+        assert!(is_suppressed(&issue, &suppressions, &en, &de));
+
+        let issue = test_issue(IssueKind::MissingKey, &de, "legal.terms.title");
+        assert!(!is_suppressed(&issue, &suppressions, &en, &de));
+    }
+}
@@ -0,0 +1,707 @@
+use crate::baseline::BaselineComparison;
+use crate::config::{CONFIG, LocaleRequirement};
+use crate::diagnostics::{Diagnostic, Location, Severity};
+use crate::document::{Document, Node};
+use crate::parser::{ObjectKeyOption, ParserError};
+use crate::{checks, files, parser, plurals, suppression};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    MissingKey,
+    EmptyValue,
+    DuplicateKey,
+    LowCoverage,
+    RemovedKey,
+    PlaceholderMismatch,
+    InvalidIcuMessage,
+    IcuArgumentMismatch,
+    IcuMissingOther,
+    MissingPluralForm,
+    SuperfluousPluralForm,
+    InvalidMarkup,
+    MarkupMismatch,
+    WhitespaceMismatch,
+    DoubleSpace,
+    PunctuationMismatch,
+    LineBreakMismatch,
+    UntranslatedValue,
+    MaxLengthExceeded,
+    ExpansionExceeded,
+    Typography,
+    InvisibleCharacter,
+    BidiControl,
+    ReplacementCharacter,
+    MixedScripts,
+    NotNfc,
+    InvalidMarker,
+}
+
+impl IssueKind {
+    pub const ALL: [IssueKind; 27] = [
+        IssueKind::MissingKey,
+        IssueKind::EmptyValue,
+        IssueKind::DuplicateKey,
+        IssueKind::LowCoverage,
+        IssueKind::RemovedKey,
+        IssueKind::PlaceholderMismatch,
+        IssueKind::InvalidIcuMessage,
+        IssueKind::IcuArgumentMismatch,
+        IssueKind::IcuMissingOther,
+        IssueKind::MissingPluralForm,
+        IssueKind::SuperfluousPluralForm,
+        IssueKind::InvalidMarkup,
+        IssueKind::MarkupMismatch,
+        IssueKind::WhitespaceMismatch,
+        IssueKind::DoubleSpace,
+        IssueKind::PunctuationMismatch,
+        IssueKind::LineBreakMismatch,
+        IssueKind::UntranslatedValue,
+        IssueKind::MaxLengthExceeded,
+        IssueKind::ExpansionExceeded,
+        IssueKind::Typography,
+        IssueKind::InvisibleCharacter,
+        IssueKind::BidiControl,
+        IssueKind::ReplacementCharacter,
+        IssueKind::MixedScripts,
+        IssueKind::NotNfc,
+        IssueKind::InvalidMarker,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IssueKind::MissingKey => "missing_key",
+            IssueKind::EmptyValue => "empty_value",
+            IssueKind::DuplicateKey => "duplicate_key",
+            IssueKind::LowCoverage => "low_coverage",
+            IssueKind::RemovedKey => "removed_key",
+            IssueKind::PlaceholderMismatch => "placeholder_mismatch",
+            IssueKind::InvalidIcuMessage => "invalid_icu_message",
+            IssueKind::IcuArgumentMismatch => "icu_argument_mismatch",
+            IssueKind::IcuMissingOther => "icu_missing_other",
+            IssueKind::MissingPluralForm => "missing_plural_form",
+            IssueKind::SuperfluousPluralForm => "superfluous_plural_form",
+            IssueKind::InvalidMarkup => "invalid_markup",
+            IssueKind::MarkupMismatch => "markup_mismatch",
+            IssueKind::WhitespaceMismatch => "whitespace_mismatch",
+            IssueKind::DoubleSpace => "double_space",
+            IssueKind::PunctuationMismatch => "punctuation_mismatch",
+            IssueKind::LineBreakMismatch => "line_break_mismatch",
+            IssueKind::UntranslatedValue => "untranslated_value",
+            IssueKind::MaxLengthExceeded => "max_length_exceeded",
+            IssueKind::ExpansionExceeded => "expansion_exceeded",
+            IssueKind::Typography => "typography",
+            IssueKind::InvisibleCharacter => "invisible_character",
+            IssueKind::BidiControl => "bidi_control",
+            IssueKind::ReplacementCharacter => "replacement_character",
+            IssueKind::MixedScripts => "mixed_scripts",
+            IssueKind::NotNfc => "not_nfc",
+            IssueKind::InvalidMarker => "invalid_marker",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            IssueKind::MissingKey => "A key of the default locale is missing from a locale file",
+            IssueKind::EmptyValue => "A translation has an empty value",
+            IssueKind::DuplicateKey => "An object contains the same key more than once",
+            IssueKind::LowCoverage => "A locale is translated for less than its required minimum",
+            IssueKind::RemovedKey => {
+                "A key removed from the default locale is still present in a locale file"
+            }
+            IssueKind::PlaceholderMismatch => {
+                "A translation has different placeholders than the default locale"
+            }
+            IssueKind::InvalidIcuMessage => "A value is not a valid ICU message",
+            IssueKind::IcuArgumentMismatch => {
+                "A translation has different ICU arguments than the default locale"
+            }
+            IssueKind::IcuMissingOther => "A plural or select argument has no 'other' branch",
+            IssueKind::MissingPluralForm => {
+                "A plural key is missing a form the plural rules of its locale need"
+            }
+            IssueKind::SuperfluousPluralForm => {
+                "A plural key has a form the plural rules of its locale do not use"
+            }
+            IssueKind::InvalidMarkup => "A value has unbalanced or improperly nested tags",
+            IssueKind::MarkupMismatch => "A translation has different tags than the default locale",
+            IssueKind::WhitespaceMismatch => {
+                "A translation has different leading or trailing whitespace than the default locale"
+            }
+            IssueKind::DoubleSpace => "A translation has a double space",
+            IssueKind::PunctuationMismatch => {
+                "A translation ends with different punctuation than the default locale"
+            }
+            IssueKind::LineBreakMismatch => {
+                "A translation has a different number of line breaks than the default locale"
+            }
+            IssueKind::UntranslatedValue => "A value is identical to the default locale's",
+            IssueKind::MaxLengthExceeded => "A value is longer than the maximum length of its key",
+            IssueKind::ExpansionExceeded => {
+                "A translation is longer than allowed relative to the default locale"
+            }
+            IssueKind::Typography => "A value does not follow the typography rules of its locale",
+            IssueKind::InvisibleCharacter => {
+                "A value contains a zero width or other invisible character"
+            }
+            IssueKind::BidiControl => {
+                "A value contains a stray or unbalanced bidi control character"
+            }
+            IssueKind::ReplacementCharacter => "A value contains the replacement character U+FFFD",
+            IssueKind::MixedScripts => {
+                "A word mixes letters from the Latin, Greek and Cyrillic scripts"
+            }
+            IssueKind::NotNfc => "A value is not in Unicode Normalization Form C",
+            IssueKind::InvalidMarker => "A marker comment above a key cannot be read",
+        }
+    }
+
+    /// The severity issues of this kind are reported with, before locale requirements and
+    /// options such as `--fail-on-duplicates` are applied.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            IssueKind::DuplicateKey
+            | IssueKind::SuperfluousPluralForm
+            | IssueKind::WhitespaceMismatch
+            | IssueKind::DoubleSpace
+            | IssueKind::PunctuationMismatch
+            | IssueKind::LineBreakMismatch
+            | IssueKind::UntranslatedValue
+            | IssueKind::ExpansionExceeded
+            | IssueKind::Typography
+            | IssueKind::MixedScripts => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub kind: IssueKind,
+    pub locale: String,
+    pub file: PathBuf,
+    pub key: String,
+    pub diagnostic: Diagnostic,
+}
+
+impl Issue {
+    pub fn severity(&self) -> Severity {
+        self.diagnostic.severity
+    }
+
+    /// The key the issue is about, or the name of the check for issues about a locale as a whole.
+    pub fn subject(&self) -> &str {
+        if self.key.is_empty() {
+            self.kind.name()
+        } else {
+            &self.key
+        }
+    }
+
+    /// A stable identifier for the issue, which does not change when lines move around. The
+    /// message tells apart issues of the same kind for the same key, such as a missing and an
+    /// extra placeholder.
+    pub fn fingerprint(&self) -> String {
+        let identity = format!(
+            "{}:{}:{}:{}",
+            self.kind.name(),
+            self.locale,
+            self.key,
+            self.diagnostic.message
+        );
+
+        // FNV-1a, as the standard library hashers are not guaranteed to be stable.
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in identity.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        format!("{:016x}", hash)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocaleFile {
+    pub locale: String,
+    pub file: PathBuf,
+    pub document: Document,
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Validation {
+    pub default_locale: LocaleFile,
+    pub locales: Vec<LocaleFile>,
+    pub issues: Vec<Issue>,
+    /// Issues left out by a suppression in the config or a marker in a locale file.
+    pub suppressed: Vec<Issue>,
+    pub baseline: Option<BaselineComparison>,
+}
+
+impl Validation {
+    pub fn error_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity() == Severity::Error)
+            .count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity() == Severity::Warning)
+            .count()
+    }
+
+    pub fn locale_issues(&self, locale: &LocaleFile) -> Vec<&Issue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.file == locale.file)
+            .collect()
+    }
+
+    pub fn coverage(&self, locale: &LocaleFile) -> Coverage {
+        coverage(&self.default_locale, locale)
+    }
+
+    /// Only keeps the issues for keys that were added or changed in the default locale since
+    /// `base`, the default locale at an earlier revision, or `None` if it did not exist yet.
+    /// Keys that were removed from the default locale but are still present in other locales
+    /// are reported as well. Returns the number of added or changed keys.
+    pub fn retain_changed(&mut self, base: Option<&Document>) -> Result<usize, ParserError> {
+        let base_keys = match base {
+            Some(base) => parser::get_translation_keys(
+                base.to_value(),
+                "".to_string(),
+                ObjectKeyOption::ExcludeObjectKeys,
+            )?,
+            None => Vec::new(),
+        };
+
+        let changed = self
+            .default_locale
+            .keys
+            .iter()
+            .filter(|key| {
+                let previous = base.and_then(|base| base.find(key));
+                let current = self.default_locale.document.find(key);
+
+                match (previous, current) {
+                    (Some(previous), Some(current)) => {
+                        previous.value.to_value() != current.value.to_value()
+                    }
+                    _ => true,
+                }
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Plural forms the default locale does not have, such as `items_few`, belong to the
+        // same group as the forms it does have.
+        let groups = plurals::plural_groups(&self.default_locale);
+        let is_changed = |key: &str| match plurals::plural_form(&groups, key) {
+            Some((group, _)) => changed
+                .iter()
+                .any(|changed| group.category(changed).is_some()),
+            None => changed.iter().any(|changed| changed == key),
+        };
+        self.issues.retain(|issue| is_changed(&issue.key));
+
+        let default_file_name = file_name(&self.default_locale.file);
+        for key in base_keys
+            .iter()
+            .filter(|key| !self.default_locale.keys.contains(key))
+        {
+            for locale in &self.locales {
+                if locale.file == self.default_locale.file || !locale.keys.contains(key) {
+                    continue;
+                }
+
+                let location = locale.document.find(key).map(|member| {
+                    Location::in_document(&locale.file, &locale.document, member.key_span)
+                });
+
+                self.issues.push(Issue {
+                    kind: IssueKind::RemovedKey,
+                    locale: locale.locale.clone(),
+                    file: locale.file.clone(),
+                    key: key.clone(),
+                    diagnostic: Diagnostic::new(
+                        IssueKind::RemovedKey.default_severity(),
+                        format!(
+                            "{} still has key '{}', which was removed from {}",
+                            file_name(&locale.file),
+                            key,
+                            default_file_name
+                        ),
+                        location,
+                    ),
+                });
+            }
+        }
+
+        Ok(changed.len())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    pub total: usize,
+    pub translated: usize,
+    pub missing: Vec<String>,
+    pub empty: Vec<String>,
+    pub extra: Vec<String>,
+}
+
+impl Coverage {
+    pub fn percentage(&self) -> f64 {
+        if self.total == 0 {
+            return 100.0;
+        }
+
+        self.translated as f64 * 100.0 / self.total as f64
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    pub fail_on_empty: bool,
+    pub fail_on_duplicates: bool,
+}
+
+pub fn validate(
+    translations_directory: PathBuf,
+    options: &ValidationOptions,
+) -> Result<Validation, ParserError> {
+    let (default_locale_path, translation_files) =
+        files::get_translation_files(translations_directory)?;
+
+    let config = CONFIG.get().unwrap();
+    let default_locale = get_locale_file(default_locale_path)?;
+    let groups = plurals::plural_groups(&default_locale);
+    let mut locales = Vec::new();
+    let mut issues = Vec::new();
+
+    for file_path in translation_files {
+        let locale_file = get_locale_file(file_path)?;
+        let file_name = file_name(&locale_file.file);
+        let mut locale_issues = Vec::new();
+
+        for duplicate in locale_file.document.duplicate_keys() {
+            let severity = if options.fail_on_duplicates {
+                Severity::Error
+            } else {
+                IssueKind::DuplicateKey.default_severity()
+            };
+
+            locale_issues.push(Issue {
+                kind: IssueKind::DuplicateKey,
+                locale: locale_file.locale.clone(),
+                file: locale_file.file.clone(),
+                key: duplicate.key.clone(),
+                diagnostic: Diagnostic::new(
+                    severity,
+                    format!("{} has duplicate key '{}'", file_name, duplicate.key),
+                    Some(Location::in_document(
+                        &locale_file.file,
+                        &locale_file.document,
+                        duplicate.duplicate,
+                    )),
+                )
+                .with_note(
+                    "first defined here",
+                    Location::in_document(
+                        &locale_file.file,
+                        &locale_file.document,
+                        duplicate.first,
+                    ),
+                ),
+            });
+        }
+
+        for key in plurals::expected_keys(&default_locale, &groups, &locale_file.locale) {
+            if locale_file.keys.contains(&key) {
+                continue;
+            }
+
+            let plural_form = plurals::plural_form(&groups, &key);
+
+            // Point at the key in the default locale, as that is what needs translating. The
+            // default locale may not have the plural form, so fall back to its `other` form.
+            let location = default_locale
+                .document
+                .find(&key)
+                .or_else(|| {
+                    let (group, _) = plural_form?;
+                    default_locale.document.find(&group.key("other"))
+                })
+                .map(|member| {
+                    Location::in_document(
+                        &default_locale.file,
+                        &default_locale.document,
+                        member.key_span,
+                    )
+                });
+
+            let (kind, message) = match plural_form {
+                Some((_, category)) => (
+                    IssueKind::MissingPluralForm,
+                    format!(
+                        "{} is missing plural form '{}' ({}), which {} needs",
+                        file_name, key, category, locale_file.locale
+                    ),
+                ),
+                None => (
+                    IssueKind::MissingKey,
+                    format!("{} is missing key '{}'", file_name, key),
+                ),
+            };
+
+            locale_issues.push(Issue {
+                kind,
+                locale: locale_file.locale.clone(),
+                file: locale_file.file.clone(),
+                key,
+                diagnostic: Diagnostic::new(kind.default_severity(), message, location),
+            });
+        }
+
+        for key in &locale_file.keys {
+            if let Some((group, category)) = plurals::plural_form(&groups, key)
+                && plurals::is_superfluous(group, category, &locale_file.locale)
+            {
+                let location = locale_file.document.find(key).map(|member| {
+                    Location::in_document(&locale_file.file, &locale_file.document, member.key_span)
+                });
+
+                locale_issues.push(Issue {
+                    kind: IssueKind::SuperfluousPluralForm,
+                    locale: locale_file.locale.clone(),
+                    file: locale_file.file.clone(),
+                    key: key.clone(),
+                    diagnostic: Diagnostic::new(
+                        IssueKind::SuperfluousPluralForm.default_severity(),
+                        format!(
+                            "{} has plural form '{}' ({}), which {} does not use",
+                            file_name, key, category, locale_file.locale
+                        ),
+                        location,
+                    ),
+                });
+            }
+        }
+
+        if options.fail_on_empty {
+            get_empty_values(
+                &locale_file,
+                &locale_file.document.root,
+                "",
+                &mut locale_issues,
+            );
+        }
+
+        locale_issues.extend(suppression::check_markers(&locale_file));
+        locale_issues.extend(checks::check_locale(&default_locale, &locale_file, config));
+
+        if locale_file.file != default_locale.file {
+            let requirement = config
+                .locale_requirements
+                .get(&locale_file.locale)
+                .copied()
+                .unwrap_or(LocaleRequirement::Required);
+
+            apply_requirement(
+                requirement,
+                &default_locale,
+                &locale_file,
+                &mut locale_issues,
+            );
+        }
+
+        issues.extend(locale_issues);
+        locales.push(locale_file);
+    }
+
+    let (suppressed, issues) = issues.into_iter().partition(|issue: &Issue| {
+        let locale = locales
+            .iter()
+            .find(|locale| locale.file == issue.file)
+            .unwrap();
+        suppression::is_suppressed(issue, &config.suppressions, &default_locale, locale)
+    });
+
+    Ok(Validation {
+        default_locale,
+        locales,
+        issues,
+        suppressed,
+        baseline: None,
+    })
+}
+
+/// Compares the keys of `locale` to the default locale. A key counts as translated
+/// when it is present and its value is not empty. Plural keys count with the forms
+/// the plural rules of `locale` need.
+pub fn coverage(default_locale: &LocaleFile, locale: &LocaleFile) -> Coverage {
+    let groups = plurals::plural_groups(default_locale);
+    let expected = plurals::expected_keys(default_locale, &groups, &locale.locale);
+    let mut missing = Vec::new();
+    let mut empty = Vec::new();
+
+    for key in &expected {
+        if !locale.keys.contains(key) {
+            missing.push(key.clone());
+            continue;
+        }
+
+        if let Some(member) = locale.document.find(key)
+            && let Node::String { value, .. } = &member.value
+            && value.is_empty()
+        {
+            empty.push(key.clone());
+        }
+    }
+
+    let extra = locale
+        .keys
+        .iter()
+        .filter(|key| !expected.contains(key) && !default_locale.keys.contains(key))
+        .cloned()
+        .collect();
+
+    Coverage {
+        total: expected.len(),
+        translated: expected.len() - missing.len() - empty.len(),
+        missing,
+        empty,
+        extra,
+    }
+}
+
+fn apply_requirement(
+    requirement: LocaleRequirement,
+    default_locale: &LocaleFile,
+    locale_file: &LocaleFile,
+    issues: &mut Vec<Issue>,
+) {
+    match requirement {
+        LocaleRequirement::Required => {}
+        LocaleRequirement::Optional => {
+            for issue in issues.iter_mut() {
+                issue.diagnostic.severity = Severity::Warning;
+            }
+        }
+        LocaleRequirement::MinCoverage(minimum) => {
+            for issue in issues.iter_mut() {
+                if matches!(
+                    issue.kind,
+                    IssueKind::MissingKey
+                        | IssueKind::EmptyValue
+                        | IssueKind::MissingPluralForm
+                        | IssueKind::UntranslatedValue
+                ) {
+                    issue.diagnostic.severity = Severity::Warning;
+                }
+            }
+
+            let percentage = coverage(default_locale, locale_file).percentage();
+            if percentage < minimum {
+                issues.push(Issue {
+                    kind: IssueKind::LowCoverage,
+                    locale: locale_file.locale.clone(),
+                    file: locale_file.file.clone(),
+                    key: String::new(),
+                    diagnostic: Diagnostic::new(
+                        IssueKind::LowCoverage.default_severity(),
+                        format!(
+                            "{} is {:.1}% translated, below the required {}%",
+                            file_name(&locale_file.file),
+                            percentage,
+                            minimum
+                        ),
+                        None,
+                    ),
+                });
+            }
+        }
+    }
+}
+
+pub fn get_locale_file(file: PathBuf) -> Result<LocaleFile, ParserError> {
+    let document = parser::get_translation_document(file.clone())?;
+    let keys = parser::get_translation_keys(
+        document.to_value(),
+        "".to_string(),
+        ObjectKeyOption::ExcludeObjectKeys,
+    )?;
+
+    let Some(locale) = file.file_stem().and_then(|stem| stem.to_str()) else {
+        return Err(ParserError::InvalidFileName(file));
+    };
+
+    Ok(LocaleFile {
+        locale: locale.to_string(),
+        file,
+        document,
+        keys,
+    })
+}
+
+fn get_empty_values(locale_file: &LocaleFile, node: &Node, key: &str, issues: &mut Vec<Issue>) {
+    match node {
+        Node::String { value, span } if value.is_empty() => {
+            issues.push(Issue {
+                kind: IssueKind::EmptyValue,
+                locale: locale_file.locale.clone(),
+                file: locale_file.file.clone(),
+                key: key.to_string(),
+                diagnostic: Diagnostic::new(
+                    IssueKind::EmptyValue.default_severity(),
+                    format!(
+                        "{} has an empty value '{}'",
+                        file_name(&locale_file.file),
+                        key
+                    ),
+                    Some(Location::in_document(
+                        &locale_file.file,
+                        &locale_file.document,
+                        *span,
+                    )),
+                ),
+            });
+        }
+        Node::Object { members, .. } => {
+            for member in members {
+                let sub_key = if key.is_empty() {
+                    member.key.clone()
+                } else {
+                    format!("{}.{}", key, member.key)
+                };
+
+                get_empty_values(locale_file, &member.value, &sub_key, issues);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// The lowercase language of `locale`, such as `pt` for `pt-BR` or `zh` for `zh_Hant`.
+pub fn locale_language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_lowercase()
+}
+
+/// The name of the file at `path` for messages. Locale files are only read when their name is
+/// valid UTF-8, so nothing is lost for them.
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .to_string()
+}